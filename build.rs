@@ -0,0 +1,53 @@
+//! Records the git revision the `aoc` binary is built from, so that `aoc run --format json` can
+//! say which commit its timings are for, even if something else is checked out by the time it
+//! runs. See `report::GIT_REVISION`.
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+/// The commit checked out in `repo`, with `-dirty` on the end if there are uncommitted changes,
+/// or `None` if it isn't a git checkout (or there is no git).
+fn git_revision(repo: &Path) -> Option<String> {
+    let git = |args: &[&str]| {
+        let output = Command::new("git")
+            .arg("-C")
+            .arg(repo)
+            .args(args)
+            .output()
+            .ok()?;
+        output
+            .status
+            .success()
+            .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+    let revision = git(&["rev-parse", "HEAD"])?;
+    let dirty = git(&["status", "--porcelain"]).is_some_and(|status| !status.is_empty());
+    Some(if dirty {
+        format!("{revision}-dirty")
+    } else {
+        revision
+    })
+}
+
+fn main() {
+    let repo = Path::new(env!("CARGO_MANIFEST_DIR"));
+    if let Some(revision) = git_revision(repo) {
+        println!("cargo:rustc-env=AOC_GIT_REVISION={revision}");
+    }
+
+    // rebuild after a commit or checkout, or after any change which makes the tree dirty or clean
+    let git_dir = repo.join(".git");
+    for path in ["HEAD", "index", "packed-refs"] {
+        println!("cargo:rerun-if-changed={}", git_dir.join(path).display());
+    }
+    if let Some(branch) = fs::read_to_string(git_dir.join("HEAD"))
+        .ok()
+        .and_then(|head| Some(head.strip_prefix("ref: ")?.trim().to_string()))
+    {
+        println!("cargo:rerun-if-changed={}", git_dir.join(branch).display());
+    }
+    for path in ["src", "tests", "Cargo.toml", "build.rs", "README.MD"] {
+        println!("cargo:rerun-if-changed={}", repo.join(path).display());
+    }
+}
@@ -0,0 +1,277 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::client::Verdict;
+use crate::input::InputSource;
+use crate::report;
+use crate::runner::{DayRun, Part};
+use crate::UNSOLVED;
+
+/// Name of the answers file, which sits next to the inputs it holds answers for.
+pub const ANSWERS_FILE: &str = "answers.txt";
+/// Name of the file of answers the site said were wrong, which sits next to the answers file.
+pub const GUESSES_FILE: &str = "guesses.txt";
+
+/// How a computed answer compares against the one on record.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail,
+    /// There is no answer on record for this day, part and input.
+    Unknown,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            Status::Pass => "PASS",
+            Status::Fail => "FAIL",
+            Status::Unknown => "UNKNOWN",
+        };
+        f.write_str(label)
+    }
+}
+
+/// Key for an input in the answers file: `real`, `example`, or for an explicit `--input` file, its
+/// name and a hash of its `text`, e.g. `big.txt@af63dc4c8601ec8c`. The name alone would mix up the
+/// answers of different files which happen to share it, or of a file which has since changed.
+/// Stdin and generated inputs have no name, so their answers can never be known.
+pub fn input_key(source: &InputSource, text: &str) -> Option<String> {
+    match source {
+        InputSource::Real => Some(String::from("real")),
+        InputSource::Example => Some(String::from("example")),
+        InputSource::File(path) => {
+            let name = path.file_name()?.to_string_lossy();
+            Some(format!("{name}@{}", report::input_hash(text)))
+        }
+        InputSource::Stdin | InputSource::Generated { .. } => None,
+    }
+}
+
+/// The known answers for every day, part and input, kept in a tab-separated file with one
+/// `day part input answer` entry per line, e.g. `06<TAB>2<TAB>real<TAB>1686`. Lines starting with
+/// `#` are comments.
+///
+/// This replaces the `assert_eq!`s which used to end every `main`, which tied the binaries to the
+/// answers for one account's inputs.
+#[derive(Debug, Default)]
+pub struct Answers {
+    path: PathBuf,
+    entries: BTreeMap<(u8, Part, String), String>,
+}
+
+impl Answers {
+    /// Loads the answers file in `inputs_dir`. A missing file just means nothing is known yet.
+    pub fn load(inputs_dir: &Path) -> io::Result<Answers> {
+        let path = inputs_dir.join(ANSWERS_FILE);
+        let mut answers = Answers {
+            path,
+            entries: BTreeMap::new(),
+        };
+        let text = match fs::read_to_string(&answers.path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(answers),
+            Err(err) => return Err(err),
+        };
+
+        for (i, line) in text.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let invalid = || {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!(
+                        "{} line {}: expected `day<TAB>part<TAB>input<TAB>answer`",
+                        answers.path.display(),
+                        i + 1
+                    ),
+                )
+            };
+            let fields: Vec<&str> = line.splitn(4, '\t').collect();
+            let [day, part, input, answer] = fields[..] else {
+                return Err(invalid());
+            };
+            let day: u8 = day.trim().parse().map_err(|_| invalid())?;
+            let part: Part = part.trim().parse().map_err(|_| invalid())?;
+            answers.entries.insert(
+                (day, part, input.trim().to_string()),
+                answer.trim().to_string(),
+            );
+        }
+        Ok(answers)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn get(&self, day: u8, part: Part, input: &str) -> Option<&str> {
+        self.entries
+            .get(&(day, part, input.to_string()))
+            .map(String::as_str)
+    }
+
+    /// Records `answer`, unless there already is one on record. Known answers are never
+    /// overwritten, since a wrong answer would then silently turn into the expected one, so an
+    /// unsolved part's [`UNSOLVED`] is never recorded either. Returns whether the answer was
+    /// recorded.
+    pub fn record(&mut self, day: u8, part: Part, input: &str, answer: &str) -> bool {
+        let key = (day, part, input.to_string());
+        if answer == UNSOLVED || self.entries.contains_key(&key) {
+            return false;
+        }
+        self.entries.insert(key, answer.to_string());
+        true
+    }
+
+    /// Replaces every answer of `day` on `input` with `answers`, for when the input itself was
+    /// replaced, so that none of the old input's answers are left behind.
+    pub fn replace(&mut self, day: u8, input: &str, answers: &[(Part, String)]) {
+        self.entries
+            .retain(|(d, _, i), _| !(*d == day && i == input));
+        for (part, answer) in answers {
+            self.entries
+                .insert((day, *part, input.to_string()), answer.clone());
+        }
+    }
+
+    /// Fills in the expected answer of every part in `run` on `input`, if there is one on record.
+    /// An unsolved part is left without one, so that it's reported as unknown rather than failed.
+    pub fn annotate(&self, run: &mut DayRun, input: &str) {
+        for part_run in &mut run.parts {
+            part_run.expected = match part_run.answer.as_str() {
+                UNSOLVED => None,
+                _ => self.get(run.day, part_run.part, input).map(String::from),
+            };
+        }
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let mut text = String::from("# day\tpart\tinput\tanswer\n");
+        for ((day, part, input), answer) in &self.entries {
+            text.push_str(&format!("{day:02}\t{part}\t{input}\t{answer}\n"));
+        }
+        fs::write(&self.path, text)
+    }
+}
+
+/// Answers to the real inputs which the site said were wrong, so that `aoc submit` never sends the
+/// same one twice, nor one that is known to be wrong because an earlier guess was too high or too
+/// low. Kept like the answers file, one `day part answer verdict` entry per line, where `verdict`
+/// is `wrong`, `high` or `low`.
+#[derive(Debug, Default)]
+pub struct Guesses {
+    path: PathBuf,
+    entries: BTreeMap<(u8, Part), Vec<(String, Verdict)>>,
+}
+
+impl Guesses {
+    /// Loads the guesses file in `inputs_dir`. A missing file just means nothing was guessed yet.
+    pub fn load(inputs_dir: &Path) -> io::Result<Guesses> {
+        let path = inputs_dir.join(GUESSES_FILE);
+        let mut guesses = Guesses {
+            path,
+            entries: BTreeMap::new(),
+        };
+        let text = match fs::read_to_string(&guesses.path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(guesses),
+            Err(err) => return Err(err),
+        };
+
+        for (i, line) in text.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let invalid = || {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!(
+                        "{} line {}: expected `day<TAB>part<TAB>answer<TAB>wrong|high|low`",
+                        guesses.path.display(),
+                        i + 1
+                    ),
+                )
+            };
+            let fields: Vec<&str> = line.split('\t').map(str::trim).collect();
+            let [day, part, answer, verdict] = fields[..] else {
+                return Err(invalid());
+            };
+            let day: u8 = day.parse().map_err(|_| invalid())?;
+            let part: Part = part.parse().map_err(|_| invalid())?;
+            let verdict = match verdict {
+                "wrong" => Verdict::Wrong,
+                "high" => Verdict::TooHigh,
+                "low" => Verdict::TooLow,
+                _ => return Err(invalid()),
+            };
+            guesses
+                .entries
+                .entry((day, part))
+                .or_default()
+                .push((answer.to_string(), verdict));
+        }
+        Ok(guesses)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Records a wrong `answer`. Returns whether it was recorded, i.e. whether `verdict` was one of
+    /// the wrong ones.
+    pub fn record(&mut self, day: u8, part: Part, answer: &str, verdict: &Verdict) -> bool {
+        if !matches!(verdict, Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow) {
+            return false;
+        }
+        self.entries
+            .entry((day, part))
+            .or_default()
+            .push((answer.to_string(), verdict.clone()));
+        true
+    }
+
+    /// Why `answer` is already known to be wrong, if it is. Besides the answers that were guessed
+    /// before, a numeric answer is ruled out by any guess it is on the wrong side of.
+    pub fn rule_out(&self, day: u8, part: Part, answer: &str) -> Option<String> {
+        let guesses = self.entries.get(&(day, part))?;
+        let number = answer.parse::<i128>().ok();
+        for (guess, verdict) in guesses {
+            if guess == answer {
+                return Some(format!("{answer} was already guessed, and was wrong"));
+            }
+            let (Some(number), Ok(guess)) = (number, guess.parse::<i128>()) else {
+                continue;
+            };
+            match verdict {
+                Verdict::TooHigh if number >= guess => {
+                    return Some(format!("{answer} is at least {guess}, which was too high"));
+                }
+                Verdict::TooLow if number <= guess => {
+                    return Some(format!("{answer} is at most {guess}, which was too low"));
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let mut text = String::from("# day\tpart\tanswer\tverdict\n");
+        for ((day, part), guesses) in &self.entries {
+            for (answer, verdict) in guesses {
+                let verdict = match verdict {
+                    Verdict::TooHigh => "high",
+                    Verdict::TooLow => "low",
+                    _ => "wrong",
+                };
+                text.push_str(&format!("{day:02}\t{part}\t{answer}\t{verdict}\n"));
+            }
+        }
+        fs::write(&self.path, text)
+    }
+}
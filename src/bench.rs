@@ -0,0 +1,352 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::hint::black_box;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use crate::error::ParseError;
+use crate::runner::Part;
+use crate::table;
+use crate::Solution;
+
+/// Each sample runs the routine enough times to take at least this long, so that routines which
+/// only take a few nanoseconds aren't drowned out by the resolution of the clock.
+const MIN_SAMPLE_TIME: Duration = Duration::from_millis(1);
+
+/// Changes in the median smaller than this are reported as noise rather than a regression or an
+/// improvement.
+const NOISE_THRESHOLD: f64 = 0.05;
+
+#[derive(Clone, Debug)]
+pub struct BenchConfig {
+    /// How long to run each routine for before taking any samples, to warm up the caches and the
+    /// branch predictor, and to estimate how many iterations make up a sample.
+    pub warm_up: Duration,
+    /// How many samples to take of each routine.
+    pub samples: usize,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        BenchConfig {
+            warm_up: Duration::from_millis(500),
+            samples: 20,
+        }
+    }
+}
+
+/// The separately timed steps of a day: parsing the input, then solving each part against the
+/// parsed input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Stage {
+    Parse,
+    Solve(Part),
+}
+
+impl Stage {
+    /// Name used in the baseline files, e.g. `part2`.
+    fn key(self) -> String {
+        match self {
+            Stage::Parse => String::from("parse"),
+            Stage::Solve(part) => format!("part{part}"),
+        }
+    }
+
+    fn from_key(key: &str) -> Option<Stage> {
+        match key {
+            "parse" => Some(Stage::Parse),
+            _ => key.strip_prefix("part")?.parse().ok().map(Stage::Solve),
+        }
+    }
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::Solve(part) => write!(f, "part {part}"),
+        }
+    }
+}
+
+/// Summary of the per-iteration times across every sample of a routine.
+#[derive(Clone, Copy, Debug)]
+pub struct Stats {
+    pub samples: usize,
+    pub iterations_per_sample: u32,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    fn from_samples(mut per_iteration: Vec<Duration>, iterations_per_sample: u32) -> Stats {
+        per_iteration.sort_unstable();
+        let n = per_iteration.len();
+        let median = if n.is_multiple_of(2) {
+            (per_iteration[n / 2 - 1] + per_iteration[n / 2]) / 2
+        } else {
+            per_iteration[n / 2]
+        };
+        // nearest-rank percentile, i.e. the smallest sample which is >= 95% of the samples
+        let p95 = per_iteration[(n * 95).div_ceil(100) - 1];
+        Stats {
+            samples: n,
+            iterations_per_sample,
+            median,
+            p95,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct StageBench {
+    pub stage: Stage,
+    pub stats: Stats,
+}
+
+#[derive(Clone, Debug)]
+pub struct DayBench {
+    pub day: u8,
+    /// See `report::input_hash`.
+    pub input_hash: String,
+    pub stages: Vec<StageBench>,
+}
+
+/// Times `routine` as described by `config`, first warming up, then taking each sample as a batch
+/// of iterations.
+fn measure<T>(config: &BenchConfig, mut routine: impl FnMut() -> T) -> Stats {
+    let warm_up_start = Instant::now();
+    let mut warm_up_iterations: u32 = 0;
+    loop {
+        black_box(routine());
+        warm_up_iterations += 1;
+        if warm_up_start.elapsed() >= config.warm_up {
+            break;
+        }
+    }
+    let estimate = warm_up_start.elapsed() / warm_up_iterations;
+    let iterations_per_sample = if estimate.is_zero() {
+        MIN_SAMPLE_TIME.as_nanos() as u32
+    } else {
+        (MIN_SAMPLE_TIME.as_nanos() / estimate.as_nanos()).clamp(1, u32::MAX as u128) as u32
+    };
+
+    let per_iteration = (0..config.samples.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            for _ in 0..iterations_per_sample {
+                black_box(routine());
+            }
+            start.elapsed() / iterations_per_sample
+        })
+        .collect();
+    Stats::from_samples(per_iteration, iterations_per_sample)
+}
+
+/// Benchmarks parsing `input`, then each of `parts` against the parsed input. Like
+/// `runner::run`, this is generic so that each day can be type-erased in the registry. An input
+/// that doesn't parse is reported before any time is spent measuring.
+pub fn bench<S: Solution>(
+    input: &str,
+    parts: &[Part],
+    config: &BenchConfig,
+) -> Result<Vec<StageBench>, ParseError> {
+    let parsed = S::parse(input)?;
+    let mut stages = vec![StageBench {
+        stage: Stage::Parse,
+        stats: measure(config, || S::parse(black_box(input))),
+    }];
+
+    for &part in parts {
+        let stats = match part {
+            Part::One => measure(config, || S::part_one(black_box(&parsed))),
+            Part::Two => measure(config, || S::part_two(black_box(&parsed))),
+        };
+        stages.push(StageBench {
+            stage: Stage::Solve(part),
+            stats,
+        });
+    }
+    Ok(stages)
+}
+
+/// Where the baselines are saved, i.e. `target/aoc-bench`, in the same spirit as criterion's
+/// `target/criterion`.
+pub fn baselines_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("target")
+        .join("aoc-bench")
+}
+
+/// Median and p95 of every day and stage from an earlier run, which a later run can be compared
+/// against, along with the hash of the input they were measured on, since timings on different
+/// inputs can't be compared. Saved as tab-separated `day stage input_hash median_ns p95_ns` lines.
+#[derive(Debug, Default)]
+pub struct Baseline {
+    entries: BTreeMap<(u8, Stage), BaselineEntry>,
+}
+
+#[derive(Clone, Debug)]
+struct BaselineEntry {
+    input_hash: String,
+    median: Duration,
+    p95: Duration,
+}
+
+impl Baseline {
+    pub fn path(name: &str) -> PathBuf {
+        baselines_dir().join(format!("{name}.txt"))
+    }
+
+    /// Loads the named baseline, or an empty one if it hasn't been saved yet.
+    pub fn load(name: &str) -> io::Result<Baseline> {
+        let path = Baseline::path(name);
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Baseline::default()),
+            Err(err) => return Err(err),
+        };
+
+        let mut baseline = Baseline::default();
+        for (i, line) in text.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let invalid = || {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!(
+                        "{} line {}: expected `day<TAB>stage<TAB>input_hash<TAB>median_ns<TAB>p95_ns`, \
+                         baselines saved before the input hash was kept need saving again",
+                        path.display(),
+                        i + 1
+                    ),
+                )
+            };
+            let fields: Vec<&str> = line.split('\t').collect();
+            let [day, stage, input_hash, median, p95] = fields[..] else {
+                return Err(invalid());
+            };
+            let day = day.parse().map_err(|_| invalid())?;
+            let stage = Stage::from_key(stage).ok_or_else(invalid)?;
+            let median = median.parse().map_err(|_| invalid())?;
+            let p95 = p95.parse().map_err(|_| invalid())?;
+            baseline.entries.insert(
+                (day, stage),
+                BaselineEntry {
+                    input_hash: input_hash.to_string(),
+                    median: Duration::from_nanos(median),
+                    p95: Duration::from_nanos(p95),
+                },
+            );
+        }
+        Ok(baseline)
+    }
+
+    /// Adds the results to the baseline, replacing any earlier results for the same stages.
+    pub fn update(&mut self, benches: &[DayBench]) {
+        for bench in benches {
+            for stage in &bench.stages {
+                self.entries.insert(
+                    (bench.day, stage.stage),
+                    BaselineEntry {
+                        input_hash: bench.input_hash.clone(),
+                        median: stage.stats.median,
+                        p95: stage.stats.p95,
+                    },
+                );
+            }
+        }
+    }
+
+    pub fn save(&self, name: &str) -> io::Result<()> {
+        fs::create_dir_all(baselines_dir())?;
+        let mut text = String::from("# day\tstage\tinput_hash\tmedian_ns\tp95_ns\n");
+        for ((day, stage), entry) in &self.entries {
+            text.push_str(&format!(
+                "{day:02}\t{}\t{}\t{}\t{}\n",
+                stage.key(),
+                entry.input_hash,
+                entry.median.as_nanos(),
+                entry.p95.as_nanos()
+            ));
+        }
+        fs::write(Baseline::path(name), text)
+    }
+
+    /// How the median of `stats`, measured on the input with `input_hash`, compares to the
+    /// baseline, e.g. `+12.5% (regressed)`. Results from a different input aren't compared, and
+    /// neither is a baseline median of zero, which a stage too quick for the clock can have.
+    pub fn compare(
+        &self,
+        day: u8,
+        stage: Stage,
+        input_hash: &str,
+        stats: &Stats,
+    ) -> Option<String> {
+        let entry = self.entries.get(&(day, stage))?;
+        if entry.input_hash != input_hash {
+            return Some(String::from("different input"));
+        }
+        if entry.median.is_zero() {
+            return Some(String::from("too quick to compare"));
+        }
+        let change = stats.median.as_secs_f64() / entry.median.as_secs_f64() - 1.0;
+        let verdict = if change > NOISE_THRESHOLD {
+            "regressed"
+        } else if change < -NOISE_THRESHOLD {
+            "improved"
+        } else {
+            "no change"
+        };
+        Some(format!("{:+.1}% ({verdict})", change * 100.0))
+    }
+
+    /// The days in `benches` which the baseline has results for, but measured on a different
+    /// input, so that `compare` won't compare them.
+    pub fn different_inputs(&self, benches: &[DayBench]) -> Vec<u8> {
+        benches
+            .iter()
+            .filter(|bench| {
+                self.entries.iter().any(|(&(day, _), entry)| {
+                    day == bench.day && entry.input_hash != bench.input_hash
+                })
+            })
+            .map(|bench| bench.day)
+            .collect()
+    }
+}
+
+/// Renders the results as an aligned table, with a column comparing each stage against
+/// `baseline` if there is one.
+pub fn format_table(benches: &[DayBench], baseline: Option<&Baseline>) -> String {
+    let mut header = vec!["Day", "Stage", "Median", "p95", "Samples"];
+    if baseline.is_some() {
+        header.push("vs baseline");
+    }
+    let mut rows: Vec<Vec<String>> = Vec::new();
+    for bench in benches {
+        for stage in &bench.stages {
+            let stats = &stage.stats;
+            let mut row = vec![
+                format!("{:02}", bench.day),
+                stage.stage.to_string(),
+                format!("{:.2?}", stats.median),
+                format!("{:.2?}", stats.p95),
+                format!("{} x {}", stats.samples, stats.iterations_per_sample),
+            ];
+            if let Some(baseline) = baseline {
+                row.push(
+                    baseline
+                        .compare(bench.day, stage.stage, &bench.input_hash, stats)
+                        .unwrap_or_else(|| String::from("-")),
+                );
+            }
+            rows.push(row);
+        }
+    }
+
+    table::render(&header, &rows)
+}
@@ -0,0 +1,199 @@
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::str::FromStr;
+
+use aoc_2024::cli::{
+    self, BenchArgs, ExtractArgs, PatrolArgs, RenderArgs, ReportsArgs, RunArgs, SimilarityArgs,
+    SiteArgs,
+};
+use aoc_2024::input::{InputArgs, InputSource};
+use aoc_2024::registry::{self, Puzzle, PUZZLES};
+use aoc_2024::runner::Part;
+use aoc_2024::scaffold;
+use clap::{Parser, Subcommand};
+
+/// Runner for every day's solution, e.g. `aoc run 6 --part 2`, `aoc run all --example`.
+#[derive(Parser)]
+#[command(name = "aoc")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a single day, or `all` of them, and print the answers and timings
+    Run {
+        /// Day number, e.g. `6`, or `all`
+        day: DaySelection,
+        #[command(flatten)]
+        args: RunArgs,
+    },
+    /// Benchmark parsing and each part of a single day, or `all` of them
+    Bench {
+        /// Day number, e.g. `6`, or `all`
+        day: DaySelection,
+        #[command(flatten)]
+        args: BenchArgs,
+    },
+    /// Download the real input for a single day, or `all` of the solved days, unless it's already
+    /// in the inputs directory
+    Fetch {
+        /// Day number, e.g. `6`, or `all`
+        day: DaySelection,
+        #[command(flatten)]
+        args: SiteArgs,
+    },
+    /// Solve one part of a day against the real input, and submit the answer to the site
+    Submit {
+        /// Day number, e.g. `6`
+        day: u8,
+        /// Part to submit, 1 or 2
+        part: Part,
+        #[command(flatten)]
+        args: SiteArgs,
+    },
+    /// Save the example input and its answers from a puzzle page saved from the browser
+    Extract {
+        /// Day number, e.g. `6`
+        day: u8,
+        #[command(flatten)]
+        args: ExtractArgs,
+    },
+    /// Check that a single day's input, or `all` of them, parses and doesn't break any of the
+    /// assumptions the solution makes about it
+    Validate {
+        /// Day number, e.g. `6`, or `all`
+        day: DaySelection,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Animate the day 06 guard's patrol in the terminal, one step at a time
+    Patrol {
+        #[command(flatten)]
+        args: PatrolArgs,
+    },
+    /// Save a picture of a grid day's input, with what its solution found drawn on top
+    Render {
+        /// Day number, e.g. `6`
+        day: u8,
+        #[command(flatten)]
+        args: RenderArgs,
+    },
+    /// List day 02's reports, whether each is safe, and why not, under rules of your choosing
+    Reports {
+        #[command(flatten)]
+        args: ReportsArgs,
+    },
+    /// Work out day 01's similarity score a line at a time, for lists too big to load
+    Similarity {
+        #[command(flatten)]
+        args: SimilarityArgs,
+    },
+    /// List every day that has a solution
+    List,
+    /// Start a new day: create its module, binary and empty input files, and register it
+    New {
+        /// Day number, e.g. `10`
+        day: u8,
+        /// Directory to create the `day_XX.txt` inputs in [env: AOC_INPUTS_DIR] [default: src/input]
+        #[arg(long, value_name = "DIR")]
+        inputs_dir: Option<PathBuf>,
+    },
+}
+
+#[derive(Clone, Copy)]
+enum DaySelection {
+    All,
+    Day(u8),
+}
+
+impl FromStr for DaySelection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "all" {
+            Ok(DaySelection::All)
+        } else {
+            s.parse()
+                .map(DaySelection::Day)
+                .map_err(|_| format!("expected a day number or `all`, got `{s}`"))
+        }
+    }
+}
+
+/// Looks up the selected days, or explains why they can't be run.
+fn select(day: DaySelection, input: &InputArgs) -> Result<Vec<&'static Puzzle>, String> {
+    match day {
+        DaySelection::All => {
+            if matches!(input.source(), InputSource::File(_) | InputSource::Stdin) {
+                return Err(String::from(
+                    "--input can only be used when running a single day",
+                ));
+            }
+            Ok(PUZZLES.iter().collect())
+        }
+        DaySelection::Day(day) => registry::find(day)
+            .map(|puzzle| vec![puzzle])
+            .ok_or_else(|| format!("day {day} has no solution yet, see `aoc list`")),
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let result = match cli.command {
+        Command::Run { day, args } => {
+            select(day, &args.input).map(|puzzles| cli::run(&puzzles, &args))
+        }
+        Command::Bench { day, args } => {
+            select(day, &args.input).map(|puzzles| cli::bench(&puzzles, &args))
+        }
+        Command::Fetch { day, args } => {
+            let days = match day {
+                DaySelection::All => Ok(PUZZLES.iter().map(|puzzle| puzzle.day).collect()),
+                // a day can be fetched before it has a solution
+                DaySelection::Day(day @ 1..=25) => Ok(vec![day]),
+                DaySelection::Day(day) => Err(format!("there is no day {day}")),
+            };
+            days.map(|days| cli::fetch(&days, &args))
+        }
+        Command::Submit { day, part, args } => registry::find(day)
+            .map(|puzzle| cli::submit(puzzle, part, &args))
+            .ok_or_else(|| format!("day {day} has no solution yet, see `aoc list`")),
+        Command::Extract { day, args } => Ok(cli::extract(day, &args)),
+        Command::Patrol { args } => Ok(cli::patrol(&args)),
+        Command::Render { day, args } => registry::find(day)
+            .map(|puzzle| cli::render(puzzle, &args))
+            .ok_or_else(|| format!("day {day} has no solution yet, see `aoc list`")),
+        Command::Reports { args } => Ok(cli::reports(&args)),
+        Command::Similarity { args } => Ok(cli::similarity(&args)),
+        Command::Validate { day, input } => {
+            select(day, &input).map(|puzzles| cli::validate(&puzzles, &input))
+        }
+        Command::List => {
+            for puzzle in PUZZLES {
+                println!("{}", puzzle.name());
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::New { day, inputs_dir } => {
+            let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+            let input = InputArgs {
+                inputs_dir,
+                ..InputArgs::default()
+            };
+            scaffold::new_day(root, &input.inputs_dir(), day)
+                .map(|touched| {
+                    for path in touched {
+                        println!("Wrote {}", path.display());
+                    }
+                    ExitCode::SUCCESS
+                })
+                .map_err(|err| format!("failed to create day {day}: {err}"))
+        }
+    };
+    result.unwrap_or_else(|err| {
+        eprintln!("{err}");
+        ExitCode::FAILURE
+    })
+}
@@ -0,0 +1,840 @@
+use std::fs;
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::ops::ControlFlow;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use clap::{Args, Parser};
+
+use crate::answers::{self, Answers, Guesses};
+use crate::bench::{self, Baseline, BenchConfig};
+use crate::client::{self, Client, ClientError, Fetched, Throttle, Verdict};
+use crate::days::day_01;
+use crate::days::day_02::{self, SafetyPolicy};
+use crate::days::day_06::{self, Step};
+use crate::extract::{self, PuzzlePage};
+use crate::grid::Grid;
+use crate::input::{self, InputArgs, InputSource};
+use crate::pool;
+use crate::registry::{self, Puzzle};
+use crate::render::{self, Color, Image};
+use crate::report::{self, Format};
+use crate::runner::{self, Part};
+use crate::{Solution, UNSOLVED};
+
+/// Flags for solving one or more days, shared by `aoc run` and the day binaries.
+#[derive(Args, Clone, Debug, Default)]
+pub struct RunArgs {
+    /// Only solve this part (1 or 2), instead of both
+    #[arg(long)]
+    pub part: Option<Part>,
+    #[command(flatten)]
+    pub input: InputArgs,
+    /// Save any answers that aren't on record yet to the answers file
+    #[arg(long)]
+    pub record: bool,
+    /// How many days to solve at once [default: one per CPU]
+    #[arg(long, short)]
+    pub jobs: Option<usize>,
+    /// How to print the results
+    #[arg(long, value_enum, default_value_t)]
+    pub format: Format,
+}
+
+impl RunArgs {
+    pub fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(part) => vec![part],
+            None => Part::BOTH.to_vec(),
+        }
+    }
+}
+
+/// Solves each of `puzzles`, prints the table of answers, and checks them against the answers
+/// file. Fails if any answer is wrong, an input could not be read or parsed, or a day panicked,
+/// but only after every day has had its turn.
+///
+/// The days are solved in parallel, so that the slow ones (looking at you, day 06) don't hold up
+/// the rest. Each day is reported on stderr as it finishes, and the table once they all have.
+pub fn run(puzzles: &[&Puzzle], args: &RunArgs) -> ExitCode {
+    let inputs_dir = args.input.inputs_dir();
+    let mut answers = match Answers::load(&inputs_dir) {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("failed to read answers: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let source = args.input.source();
+    let parts = args.parts();
+
+    let mut status = ExitCode::SUCCESS;
+    let mut inputs = Vec::new();
+    for &puzzle in puzzles {
+        match args.input.load(puzzle.day) {
+            Ok(text) => {
+                let input_key = answers::input_key(&source, &text);
+                inputs.push((puzzle, text, input_key));
+            }
+            Err(err) => {
+                eprintln!("{}: failed to read input: {err}", puzzle.name());
+                status = ExitCode::FAILURE;
+            }
+        }
+    }
+
+    // only a warning, since the answer may well be right anyway
+    for (puzzle, text, _) in &inputs {
+        for violation in puzzle.validate(text) {
+            eprintln!("warning: {violation}");
+        }
+    }
+
+    let threads = args
+        .jobs
+        .unwrap_or_else(pool::default_threads)
+        .clamp(1, inputs.len().max(1));
+    let start = Instant::now();
+    let results = pool::run(
+        &inputs,
+        threads,
+        |(puzzle, text, _)| puzzle.run(text, &parts),
+        |i, result| {
+            let name = inputs[i].0.name();
+            match result {
+                Ok(Ok(run)) if inputs.len() > 1 => {
+                    eprintln!("{name} done in {:.2?}", run.total_elapsed());
+                }
+                Ok(Ok(_)) => {}
+                Ok(Err(err)) => eprintln!("{err}"),
+                Err(panic) => eprintln!("{name}: panicked: {panic}"),
+            }
+        },
+    );
+    let wall_elapsed = start.elapsed();
+
+    // the answers file key of each run's input, alongside `runs`
+    let mut runs = Vec::new();
+    let mut input_keys = Vec::new();
+    for (result, (_, _, input_key)) in results.into_iter().zip(&inputs) {
+        let Ok(Ok(mut run)) = result else {
+            // already reported as it happened
+            status = ExitCode::FAILURE;
+            continue;
+        };
+        if let Some(input_key) = input_key {
+            answers.annotate(&mut run, input_key);
+        }
+        if run.has_failures() {
+            status = ExitCode::FAILURE;
+        }
+        runs.push(run);
+        input_keys.push(input_key.clone());
+    }
+    match args.format {
+        Format::Table => {
+            print!("{}", runner::format_table(&runs));
+            if inputs.len() > 1 {
+                println!("Wall time: {wall_elapsed:.2?} on {threads} thread(s)");
+            }
+        }
+        Format::Json | Format::Csv => {
+            // every day has the same key, except for an `--input` file, which is only ever one day
+            let input_name = input_keys
+                .first()
+                .cloned()
+                .flatten()
+                .unwrap_or_else(|| source.name());
+            let records = report::records(&runs, &input_name, report::GIT_REVISION);
+            if args.format == Format::Json {
+                print!("{}", report::to_json(&records));
+            } else {
+                print!("{}", report::to_csv(&records));
+            }
+        }
+    }
+
+    if args.record {
+        if inputs.iter().any(|(_, _, input_key)| input_key.is_none()) {
+            eprintln!("answers for {} can't be recorded", source.name());
+            return ExitCode::FAILURE;
+        }
+        let mut recorded = 0;
+        for (run, input_key) in runs.iter().zip(input_keys.iter().flatten()) {
+            for part_run in &run.parts {
+                if answers.record(run.day, part_run.part, input_key, &part_run.answer) {
+                    recorded += 1;
+                }
+            }
+        }
+        if recorded > 0 {
+            if let Err(err) = answers.save() {
+                eprintln!("failed to save answers: {err}");
+                return ExitCode::FAILURE;
+            }
+        }
+        // on stderr, so as not to get mixed up with JSON or CSV output
+        eprintln!(
+            "Recorded {recorded} new answer(s) in {}",
+            answers.path().display()
+        );
+    }
+    status
+}
+
+/// Checks each of `puzzles`' input parses, and doesn't break any of the assumptions its solution
+/// makes, see `Solution::validate`. Fails if any of them does.
+pub fn validate(puzzles: &[&Puzzle], args: &InputArgs) -> ExitCode {
+    let mut status = ExitCode::SUCCESS;
+    for puzzle in puzzles {
+        let text = match args.load(puzzle.day) {
+            Ok(text) => text,
+            Err(err) => {
+                eprintln!("{}: failed to read input: {err}", puzzle.name());
+                status = ExitCode::FAILURE;
+                continue;
+            }
+        };
+        // solving no parts at all just parses the input
+        if let Err(err) = puzzle.run(&text, &[]) {
+            println!("{err}");
+            status = ExitCode::FAILURE;
+            continue;
+        }
+        let violations = puzzle.validate(&text);
+        if violations.is_empty() {
+            println!("{}: ok", puzzle.name());
+        } else {
+            status = ExitCode::FAILURE;
+        }
+        for violation in violations {
+            println!("{violation}");
+        }
+    }
+    status
+}
+
+/// Flags for `aoc bench`.
+#[derive(Args, Clone, Debug)]
+pub struct BenchArgs {
+    /// Only benchmark this part (1 or 2), as well as parsing
+    #[arg(long)]
+    pub part: Option<Part>,
+    #[command(flatten)]
+    pub input: InputArgs,
+    /// How long to warm up each routine for before sampling, in milliseconds
+    #[arg(long, value_name = "MS", default_value_t = 500)]
+    pub warm_up_ms: u64,
+    /// How many samples to take of each routine
+    #[arg(long, default_value_t = 20)]
+    pub samples: usize,
+    /// Save the results as the named baseline, e.g. `main`, merging them into any earlier results
+    #[arg(long, value_name = "NAME")]
+    pub save_baseline: Option<String>,
+    /// Compare the results against the named baseline
+    #[arg(long, value_name = "NAME")]
+    pub baseline: Option<String>,
+}
+
+/// Benchmarks each of `puzzles`, prints the timings, and saves or compares against a baseline if
+/// asked to.
+pub fn bench(puzzles: &[&Puzzle], args: &BenchArgs) -> ExitCode {
+    let config = BenchConfig {
+        warm_up: Duration::from_millis(args.warm_up_ms),
+        samples: args.samples,
+    };
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => Part::BOTH.to_vec(),
+    };
+    let baseline = match args.baseline.as_deref().map(Baseline::load) {
+        None => None,
+        Some(Ok(baseline)) => Some(baseline),
+        Some(Err(err)) => {
+            eprintln!("failed to read baseline: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut status = ExitCode::SUCCESS;
+    let mut benches = Vec::new();
+    for puzzle in puzzles {
+        let text = match args.input.load(puzzle.day) {
+            Ok(text) => text,
+            Err(err) => {
+                eprintln!("{}: failed to read input: {err}", puzzle.name());
+                status = ExitCode::FAILURE;
+                continue;
+            }
+        };
+        match puzzle.bench(&text, &parts, &config) {
+            Ok(bench) => benches.push(bench),
+            Err(err) => {
+                eprintln!("{err}");
+                status = ExitCode::FAILURE;
+            }
+        }
+    }
+    print!("{}", bench::format_table(&benches, baseline.as_ref()));
+    if let (Some(baseline), Some(name)) = (&baseline, &args.baseline) {
+        for day in baseline.different_inputs(&benches) {
+            eprintln!(
+                "warning: day_{day:02}'s results in baseline `{name}` were measured on a different input, so they aren't compared"
+            );
+        }
+    }
+
+    if let Some(name) = &args.save_baseline {
+        let saved = Baseline::load(name).and_then(|mut saved| {
+            saved.update(&benches);
+            saved.save(name)
+        });
+        match saved {
+            Ok(()) => println!("Saved baseline to {}", Baseline::path(name).display()),
+            Err(err) => {
+                eprintln!("failed to save baseline: {err}");
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+    status
+}
+
+/// Flags for the commands that talk to the site, `aoc fetch` and `aoc submit`.
+#[derive(Args, Clone, Debug)]
+pub struct SiteArgs {
+    /// Directory to save the `day_XX.txt` inputs in [env: AOC_INPUTS_DIR] [default: src/input]
+    #[arg(long, value_name = "DIR")]
+    pub inputs_dir: Option<PathBuf>,
+    /// Site to download from, e.g. a local stub server [env: AOC_BASE_URL] [default: https://adventofcode.com]
+    #[arg(long, value_name = "URL")]
+    pub base_url: Option<String>,
+}
+
+impl SiteArgs {
+    fn inputs_dir(&self) -> PathBuf {
+        InputArgs {
+            inputs_dir: self.inputs_dir.clone(),
+            ..InputArgs::default()
+        }
+        .inputs_dir()
+    }
+
+    fn connect(&self) -> Result<Client, ClientError> {
+        let session = client::load_session()?;
+        let base_url = client::base_url(self.base_url.as_deref());
+        Ok(Client::new(&base_url, session, Throttle::default()))
+    }
+}
+
+/// Downloads the real input of each of `days` that isn't in the inputs directory yet. The session
+/// token is only needed if there is something to download.
+pub fn fetch(days: &[u8], args: &SiteArgs) -> ExitCode {
+    let inputs_dir = args.inputs_dir();
+
+    let mut missing = Vec::new();
+    for &day in days {
+        match client::cached_input(&inputs_dir, day) {
+            Some(path) => println!("Already have {}", path.display()),
+            None => missing.push(day),
+        }
+    }
+    if missing.is_empty() {
+        return ExitCode::SUCCESS;
+    }
+
+    let client = match args.connect() {
+        Ok(client) => client,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut status = ExitCode::SUCCESS;
+    for day in missing {
+        match client::fetch_input(&client, &inputs_dir, day) {
+            Ok(Fetched::Downloaded(path)) => println!("Downloaded {}", path.display()),
+            Ok(Fetched::Cached(path)) => println!("Already have {}", path.display()),
+            Err(err) => {
+                eprintln!("day_{day:02}: failed to download input: {err}");
+                status = ExitCode::FAILURE;
+            }
+        }
+    }
+    status
+}
+
+/// Solves `part` of `puzzle` against the real input and submits the answer, unless it is on record
+/// already or is known to be wrong from an earlier guess. The verdict is recorded either way: a
+/// right answer in the answers file, and a wrong one in the guesses file.
+pub fn submit(puzzle: &Puzzle, part: Part, args: &SiteArgs) -> ExitCode {
+    let inputs_dir = args.inputs_dir();
+    let loaded = Answers::load(&inputs_dir).and_then(|answers| {
+        let guesses = Guesses::load(&inputs_dir)?;
+        Ok((answers, guesses))
+    });
+    let (mut answers, mut guesses) = match loaded {
+        Ok(loaded) => loaded,
+        Err(err) => {
+            eprintln!("failed to read answers: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let run = input::load(&InputSource::Real, &inputs_dir, puzzle.day)
+        .map_err(|err| format!("failed to read input: {err}"))
+        .and_then(|text| puzzle.run(&text, &[part]).map_err(|err| err.to_string()));
+    let answer = match run {
+        Ok(mut run) => run.parts.remove(0).answer,
+        Err(err) => {
+            eprintln!("{}: {err}", puzzle.name());
+            return ExitCode::FAILURE;
+        }
+    };
+    let label = format!("{} part {part}", puzzle.name());
+    if answer == UNSOLVED {
+        eprintln!("{label}: not submitting, it isn't solved yet");
+        return ExitCode::FAILURE;
+    }
+
+    match answers.get(puzzle.day, part, "real") {
+        Some(expected) if expected == answer => {
+            println!("{label}: {answer} is already on record as the right answer");
+            return ExitCode::SUCCESS;
+        }
+        Some(expected) => {
+            eprintln!("{label}: not submitting {answer}, the answer on record is {expected}");
+            return ExitCode::FAILURE;
+        }
+        None => {}
+    }
+    if let Some(reason) = guesses.rule_out(puzzle.day, part, &answer) {
+        eprintln!("{label}: not submitting, {reason}");
+        return ExitCode::FAILURE;
+    }
+
+    println!("{label}: submitting {answer}");
+    let verdict = match args
+        .connect()
+        .and_then(|client| client.submit(puzzle.day, part, &answer))
+    {
+        Ok(verdict) => verdict,
+        Err(err) => {
+            eprintln!("{label}: failed to submit: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let (message, saved) = match &verdict {
+        Verdict::Correct => {
+            answers.record(puzzle.day, part, "real", &answer);
+            ("That's the right answer!".to_string(), answers.save())
+        }
+        Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow => {
+            guesses.record(puzzle.day, part, &answer, &verdict);
+            let how = match verdict {
+                Verdict::TooHigh => "too high",
+                Verdict::TooLow => "too low",
+                _ => "wrong",
+            };
+            (format!("That's {how}."), guesses.save())
+        }
+        Verdict::RateLimited { wait } => {
+            let wait = wait.as_deref().unwrap_or("a while");
+            eprintln!("{label}: an answer was submitted too recently, try again in {wait}");
+            return ExitCode::FAILURE;
+        }
+        Verdict::AlreadySolved => {
+            eprintln!("{label}: already solved, so the site won't check the answer");
+            return ExitCode::FAILURE;
+        }
+        Verdict::Unrecognised(text) => {
+            eprintln!("{label}: unrecognised response: {text}");
+            return ExitCode::FAILURE;
+        }
+    };
+    println!("{message}");
+    if let Err(err) = saved {
+        eprintln!("failed to save the verdict: {err}");
+        return ExitCode::FAILURE;
+    }
+    if verdict == Verdict::Correct {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+/// Flags for `aoc extract`.
+#[derive(Args, Clone, Debug)]
+pub struct ExtractArgs {
+    /// The puzzle description, as saved from the browser
+    pub page: PathBuf,
+    /// Which of the page's code blocks is the example input, counting from 0
+    #[arg(long, default_value_t = 0)]
+    pub block: usize,
+    /// Replace an existing, different example input, and its answers on record
+    #[arg(long)]
+    pub force: bool,
+    /// Directory to save the `day_XX_test.txt` input in [env: AOC_INPUTS_DIR] [default: src/input]
+    #[arg(long, value_name = "DIR")]
+    pub inputs_dir: Option<PathBuf>,
+}
+
+/// Saves the example input and answers from a saved puzzle page for `day`, and says what it
+/// found, since which code block is the example is only a guess.
+pub fn extract(day: u8, args: &ExtractArgs) -> ExitCode {
+    let html = match fs::read_to_string(&args.page) {
+        Ok(html) => html,
+        Err(err) => {
+            eprintln!("failed to read {}: {err}", args.page.display());
+            return ExitCode::FAILURE;
+        }
+    };
+    let page = PuzzlePage::parse(&html);
+    for (i, block) in page.code_blocks().enumerate() {
+        let first_line = block.lines().next().unwrap_or_default();
+        let lines = block.lines().count();
+        println!("block {i}: {first_line} ... ({lines} line(s))");
+    }
+    let Some(example) = page.example(args.block) else {
+        eprintln!(
+            "{} has no code block {}, see the blocks above",
+            args.page.display(),
+            args.block
+        );
+        return ExitCode::FAILURE;
+    };
+
+    let inputs_dir = InputArgs {
+        inputs_dir: args.inputs_dir.clone(),
+        ..InputArgs::default()
+    }
+    .inputs_dir();
+    let saved = match extract::save(&inputs_dir, day, &example, args.force) {
+        Ok(saved) => saved,
+        Err(err) => {
+            eprintln!("failed to save the example: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    println!(
+        "Saved block {} to {}",
+        args.block,
+        saved.input_path.display()
+    );
+    for (part, answer) in &example.answers {
+        if saved.recorded.contains(part) {
+            println!("Recorded {answer} as the example answer to part {part}");
+        } else if saved.kept.contains(part) {
+            println!(
+                "Found {answer} for part {part}, but kept the different answer on record, fix it by hand if it's wrong"
+            );
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+/// Flags for `aoc patrol`.
+#[derive(Args, Clone, Debug)]
+pub struct PatrolArgs {
+    #[command(flatten)]
+    pub input: InputArgs,
+    /// Put one more obstruction on the map at this line and column, e.g. `7,4`, to watch the loop
+    /// it causes
+    #[arg(long, value_name = "LINE,COLUMN", value_parser = parse_cell)]
+    pub obstruction: Option<(usize, usize)>,
+    /// Just list every obstruction which causes a loop, i.e. what part two counts
+    #[arg(long, conflicts_with = "obstruction")]
+    pub loops: bool,
+    /// How long to show each step for
+    #[arg(long, value_name = "MS", default_value_t = 50)]
+    pub delay_ms: u64,
+    /// Wait for Enter after each step instead, or `q` and Enter to stop
+    #[arg(long)]
+    pub step: bool,
+    /// Only show this many rows and columns around the guard [default: the whole map]
+    #[arg(long, value_name = "SIZE")]
+    pub view: Option<usize>,
+}
+
+/// A 1-based `line,column` position, as in error messages, into a 0-based grid position.
+fn parse_cell(s: &str) -> Result<(usize, usize), String> {
+    let position = s.split_once(',').and_then(|(line, column)| {
+        let line: usize = line.trim().parse().ok()?;
+        let column: usize = column.trim().parse().ok()?;
+        Some((line.checked_sub(1)?, column.checked_sub(1)?))
+    });
+    position.ok_or_else(|| format!("expected `line,column` counting from 1, got `{s}`"))
+}
+
+/// Day 06's map, as long as it parses and has exactly one guard on it.
+fn patrol_map(input: &InputArgs) -> Result<Grid<char>, String> {
+    let text = input
+        .load(6)
+        .map_err(|err| format!("failed to read input: {err}"))?;
+    let puzzle = registry::find(6).expect("day 06 is registered");
+    if let Some(violation) = puzzle.validate(&text).first() {
+        return Err(violation.to_string());
+    }
+    day_06::Day06::parse(&text).map_err(|err| err.in_day(6).to_string())
+}
+
+/// Animates the day 06 guard's patrol in the terminal, redrawing the map after every step, or
+/// lists the obstructions which make it loop.
+pub fn patrol(args: &PatrolArgs) -> ExitCode {
+    let grid = match patrol_map(&args.input) {
+        Ok(grid) => grid,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if args.loops {
+        let obstructions = day_06::loop_obstructions(&grid);
+        for (row, col) in &obstructions {
+            println!("{},{}", row + 1, col + 1);
+        }
+        eprintln!("{} obstructions cause a loop", obstructions.len());
+        return ExitCode::SUCCESS;
+    }
+
+    let color = io::stdout().is_terminal();
+    let mut stdin = io::stdin().lock();
+    let ended = day_06::patrol(&grid, args.obstruction, |frame| {
+        // move to the top left and clear the screen, so each frame replaces the last
+        print!("\x1b[H\x1b[2J{}", frame.render(args.view, color));
+        if args.step {
+            print!("  [Enter: next step, q: stop] ");
+            let _ = io::stdout().flush();
+            let mut line = String::new();
+            if stdin.read_line(&mut line).map_or(true, |read| read == 0) || line.trim() == "q" {
+                return ControlFlow::Break(());
+            }
+        } else {
+            println!();
+            let _ = io::stdout().flush();
+            thread::sleep(Duration::from_millis(args.delay_ms));
+        }
+        ControlFlow::Continue(())
+    });
+    match ended {
+        Ok(Some(Step::Left)) => println!("\nThe guard left the map"),
+        Ok(Some(Step::Looped)) => println!("\nThe guard is going round in a loop"),
+        Ok(_) => println!(),
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+/// Flags for `aoc render`.
+#[derive(Args, Clone, Debug)]
+pub struct RenderArgs {
+    #[command(flatten)]
+    pub input: InputArgs,
+    /// Where to save the picture, as an SVG or PNG depending on the extension
+    #[arg(long, short, value_name = "PATH")]
+    pub output: PathBuf,
+    /// Only draw these layers, e.g. `--layer visited`, instead of all of them
+    #[arg(long, value_name = "NAME")]
+    pub layer: Vec<String>,
+    /// Colours for a layer, or for the `grid` itself, e.g. `visited=#9ec5fe,#a3e4a3`
+    #[arg(long, value_name = "NAME=COLORS", value_parser = parse_layer_palette)]
+    pub palette: Vec<(String, Vec<Color>)>,
+    /// Width and height of each cell, in pixels
+    #[arg(long, value_name = "PX", default_value_t = 12, value_parser = clap::value_parser!(u32).range(1..))]
+    pub cell_size: u32,
+}
+
+fn parse_layer_palette(s: &str) -> Result<(String, Vec<Color>), String> {
+    let (name, colors) = s
+        .split_once('=')
+        .ok_or_else(|| format!("expected `layer=#rrggbb,...`, got `{s}`"))?;
+    Ok((name.to_string(), render::parse_palette(colors)?))
+}
+
+/// Draws `puzzle`'s grid with the layers of what its solution found, and saves it.
+pub fn render(puzzle: &Puzzle, args: &RenderArgs) -> ExitCode {
+    let Some(layers) = render::day_layers(puzzle.day) else {
+        eprintln!("{} has nothing to draw, only grid days do", puzzle.name());
+        return ExitCode::FAILURE;
+    };
+    let text = match args.input.load(puzzle.day) {
+        Ok(text) => text,
+        Err(err) => {
+            eprintln!("{}: failed to read input: {err}", puzzle.name());
+            return ExitCode::FAILURE;
+        }
+    };
+    // the layers are drawn by the solution, which relies on the input parsing, and on the same
+    // assumptions; solving no parts at all just parses it
+    if let Err(err) = puzzle.run(&text, &[]) {
+        eprintln!("{err}");
+        return ExitCode::FAILURE;
+    }
+    if let Some(violation) = puzzle.validate(&text).first() {
+        eprintln!("{violation}");
+        return ExitCode::FAILURE;
+    }
+    let grid = match Grid::parse(&text) {
+        Ok(grid) => grid,
+        Err(err) => {
+            eprintln!("{}", err.in_day(puzzle.day));
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut layers = layers(&grid);
+    let unknown: Vec<&String> = args
+        .layer
+        .iter()
+        .filter(|name| !layers.iter().any(|layer| layer.name == name.as_str()))
+        .collect();
+    if let Some(name) = unknown.first() {
+        let names: Vec<&str> = layers.iter().map(|layer| layer.name).collect();
+        eprintln!(
+            "there is no `{name}` layer, try one of {}",
+            names.join(", ")
+        );
+        return ExitCode::FAILURE;
+    }
+    if !args.layer.is_empty() {
+        layers.retain(|layer| args.layer.iter().any(|name| name == layer.name));
+    }
+
+    let mut image = Image::new(&grid, layers);
+    image.cell_size = args.cell_size;
+    for (name, palette) in &args.palette {
+        if let Err(err) = image.set_palette(name, palette.clone()) {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    }
+    if let Err(err) = image.save(&args.output) {
+        eprintln!("failed to save the picture: {err}");
+        return ExitCode::FAILURE;
+    }
+    for layer in &image.layers {
+        println!("{}: {} cells", layer.name, layer.cells.len());
+    }
+    println!("Saved {}", args.output.display());
+    ExitCode::SUCCESS
+}
+
+/// Flags for `aoc similarity`.
+#[derive(Args, Clone, Debug)]
+pub struct SimilarityArgs {
+    /// File of location ID lists to score, or `-` for stdin
+    #[arg(value_name = "PATH")]
+    pub path: PathBuf,
+}
+
+/// Prints day 01's similarity score of a file, however big, without loading it into memory.
+pub fn similarity(args: &SimilarityArgs) -> ExitCode {
+    let score = if args.path.as_os_str() == "-" {
+        day_01::similarity_streaming(io::stdin().lock())
+    } else {
+        fs::File::open(&args.path)
+            .and_then(|file| day_01::similarity_streaming(io::BufReader::new(file)))
+    };
+    match score {
+        Ok(score) => {
+            println!("{score}");
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("{}: {err}", args.path.display());
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Flags for `aoc reports`.
+#[derive(Args, Clone, Debug)]
+pub struct ReportsArgs {
+    #[command(flatten)]
+    pub input: InputArgs,
+    /// Smallest step allowed from one level to the next
+    #[arg(long, value_name = "STEP", default_value_t = 1)]
+    pub min_step: u32,
+    /// Biggest step allowed from one level to the next
+    #[arg(long, value_name = "STEP", default_value_t = 3)]
+    pub max_step: u32,
+    /// Let the levels go up and down, rather than only ever one way
+    #[arg(long)]
+    pub direction_can_change: bool,
+    /// How many levels can be taken out to make a report safe, e.g. 1 for part two
+    #[arg(long, value_name = "LEVELS", default_value_t = 0)]
+    pub tolerance: usize,
+    /// Only list the reports which aren't safe
+    #[arg(long)]
+    pub unsafe_only: bool,
+}
+
+/// Lists every day 02 report, whether it's safe, and why not, under the rules from `args`.
+pub fn reports(args: &ReportsArgs) -> ExitCode {
+    // an empty range of steps would quietly make every report unsafe
+    if args.min_step > args.max_step {
+        eprintln!(
+            "--min-step {} is bigger than --max-step {}",
+            args.min_step, args.max_step
+        );
+        return ExitCode::FAILURE;
+    }
+    let text = match args.input.load(2) {
+        Ok(text) => text,
+        Err(err) => {
+            eprintln!("failed to read input: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let policy = SafetyPolicy {
+        steps: args.min_step..=args.max_step,
+        direction_can_change: args.direction_can_change,
+        tolerance: args.tolerance,
+    };
+    let explanations = match day_02::explain(&text, &policy) {
+        Ok(explanations) => explanations,
+        Err(err) => {
+            eprintln!("{}", err.in_day(2));
+            return ExitCode::FAILURE;
+        }
+    };
+
+    for explanation in &explanations {
+        if args.unsafe_only && explanation.safe {
+            continue;
+        }
+        let verdict = if explanation.safe { "safe" } else { "unsafe" };
+        match &explanation.diagnosis {
+            Some(diagnosis) => println!("line {}: {verdict}, {diagnosis}", explanation.line),
+            None => println!("line {}: {verdict}", explanation.line),
+        }
+    }
+    let safe = explanations.iter().filter(|explanation| explanation.safe);
+    eprintln!(
+        "{} of {} reports are safe",
+        safe.count(),
+        explanations.len()
+    );
+    ExitCode::SUCCESS
+}
+
+#[derive(Parser)]
+struct DayCli {
+    #[command(flatten)]
+    run: RunArgs,
+}
+
+/// The whole `main` of a day binary, which is the same as `aoc run <day>`.
+pub fn day_main(day: u8) -> ExitCode {
+    let cli = DayCli::parse();
+    let puzzle = registry::find(day).expect("day binaries should only exist for registered days");
+    run(&[puzzle], &cli.run)
+}
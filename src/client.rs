@@ -0,0 +1,313 @@
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use regex::Regex;
+
+use crate::input;
+use crate::runner::Part;
+
+/// The site itself. Point [`BASE_URL_ENV`] or `--base-url` somewhere else to test against a stub.
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+/// Environment variable that overrides the base URL.
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+/// Environment variable holding the session token, i.e. the value of the `session` cookie.
+pub const SESSION_ENV: &str = "AOC_SESSION";
+/// File in the home directory that holds the session token if the environment variable isn't
+/// set. This is the same file other AoC tools read, so one token works for all of them.
+pub const SESSION_FILE: &str = ".adventofcode.session";
+/// The site asks that automated requests say where they come from.
+pub const USER_AGENT: &str = concat!(
+    "github.com/cy6581/aoc_2024_rs (aoc_2024/",
+    env!("CARGO_PKG_VERSION"),
+    ")"
+);
+/// Least time to leave between two requests to the site, however many `aoc` processes there are.
+pub const MIN_REQUEST_INTERVAL: Duration = Duration::from_secs(3);
+
+const YEAR: u16 = 2024;
+
+#[derive(Debug)]
+pub enum ClientError {
+    /// No session token in the environment or the session file.
+    NoSession,
+    /// The site answered, but not with a success.
+    Status {
+        code: u16,
+        body: String,
+    },
+    /// The site couldn't be reached at all.
+    Transport(String),
+    Io(io::Error),
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::NoSession => write!(
+                f,
+                "no session token, set {SESSION_ENV} or put it in ~/{SESSION_FILE}"
+            ),
+            ClientError::Status { code, body } => {
+                write!(f, "the site responded with {code}")?;
+                match body.lines().next() {
+                    Some(line) if !line.trim().is_empty() => write!(f, ": {}", line.trim()),
+                    _ => Ok(()),
+                }
+            }
+            ClientError::Transport(err) => write!(f, "request failed: {err}"),
+            ClientError::Io(err) => err.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+impl From<io::Error> for ClientError {
+    fn from(err: io::Error) -> Self {
+        ClientError::Io(err)
+    }
+}
+
+impl From<ureq::Error> for ClientError {
+    fn from(err: ureq::Error) -> Self {
+        match err {
+            ureq::Error::Status(code, response) => ClientError::Status {
+                code,
+                body: response.into_string().unwrap_or_default(),
+            },
+            ureq::Error::Transport(err) => ClientError::Transport(err.to_string()),
+        }
+    }
+}
+
+/// The session token from [`SESSION_ENV`], or failing that the session file.
+pub fn load_session() -> Result<String, ClientError> {
+    let from_env = env::var(SESSION_ENV).ok();
+    let from_file = || {
+        let home = env::var_os("HOME")?;
+        fs::read_to_string(Path::new(&home).join(SESSION_FILE)).ok()
+    };
+    from_env
+        .or_else(from_file)
+        .map(|token| token.trim().to_string())
+        .filter(|token| !token.is_empty())
+        .ok_or(ClientError::NoSession)
+}
+
+/// The `--base-url` flag if given, then the environment variable, then the real site.
+pub fn base_url(flag: Option<&str>) -> String {
+    flag.map(String::from)
+        .or_else(|| env::var(BASE_URL_ENV).ok())
+        .unwrap_or_else(|| String::from(DEFAULT_BASE_URL))
+}
+
+/// Spaces requests out by at least `interval`. The time of the last request is kept in a file
+/// rather than in memory, so that back to back `aoc` invocations are throttled too, and the file is
+/// locked while waiting, so that `aoc` processes running at the same time take turns.
+pub struct Throttle {
+    stamp: PathBuf,
+    interval: Duration,
+}
+
+impl Throttle {
+    pub fn new(stamp: PathBuf, interval: Duration) -> Throttle {
+        Throttle { stamp, interval }
+    }
+
+    /// Sleeps until `interval` has passed since the last request, then records a new one. Holds
+    /// an exclusive lock on the stamp file throughout, so any other process waiting on the same
+    /// file reads the new time rather than the one before it.
+    pub fn wait(&self) -> io::Result<()> {
+        if let Some(dir) = self.stamp.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut file = fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(&self.stamp)?;
+        // released when the file is closed
+        file.lock()?;
+
+        let mut millis = String::new();
+        file.read_to_string(&mut millis)?;
+        let last = millis
+            .trim()
+            .parse()
+            .ok()
+            .map(|millis| UNIX_EPOCH + Duration::from_millis(millis));
+        if let Some(since) = last.and_then(|last| SystemTime::now().duration_since(last).ok()) {
+            if since < self.interval {
+                thread::sleep(self.interval - since);
+            }
+        }
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        file.set_len(0)?;
+        file.seek(SeekFrom::Start(0))?;
+        file.write_all(now.as_millis().to_string().as_bytes())
+    }
+}
+
+impl Default for Throttle {
+    /// [`MIN_REQUEST_INTERVAL`], with the time of the last request kept under `target`.
+    fn default() -> Self {
+        let stamp = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("target")
+            .join("aoc-http")
+            .join("last-request");
+        Throttle::new(stamp, MIN_REQUEST_INTERVAL)
+    }
+}
+
+/// Talks to the site on behalf of one session.
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+    throttle: Throttle,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: String, throttle: Throttle) -> Client {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            .build();
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+            agent,
+            throttle,
+        }
+    }
+
+    fn url(&self, day: u8, path: &str) -> String {
+        format!("{}/{YEAR}/day/{day}{path}", self.base_url)
+    }
+
+    /// Downloads the puzzle input for `day`. Prefer [`fetch_input`], which only does this once.
+    pub fn input(&self, day: u8) -> Result<String, ClientError> {
+        self.throttle.wait()?;
+        let response = self
+            .agent
+            .get(&self.url(day, "/input"))
+            .set("Cookie", &format!("session={}", self.session))
+            .call()?;
+        Ok(response.into_string()?)
+    }
+
+    /// Submits `answer` to `part` of `day`, and reports what the site made of it.
+    pub fn submit(&self, day: u8, part: Part, answer: &str) -> Result<Verdict, ClientError> {
+        self.throttle.wait()?;
+        let level = part.number().to_string();
+        let response = self
+            .agent
+            .post(&self.url(day, "/answer"))
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &level), ("answer", answer)])?;
+        Ok(Verdict::parse(&response.into_string()?))
+    }
+}
+
+/// What the site made of a submitted answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    /// Wrong, without saying which way.
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// An answer was submitted too recently. `wait` is how much longer the site asked for, e.g.
+    /// `37s`, if it said.
+    RateLimited {
+        wait: Option<String>,
+    },
+    /// The part was already solved, so the site won't say whether the answer is right.
+    AlreadySolved,
+    /// None of the above. Holds the text of the response, so it can be shown as is.
+    Unrecognised(String),
+}
+
+impl Verdict {
+    /// Reads the verdict out of the page the site responds to a submission with. Only the
+    /// `<article>` holds the verdict, the rest of the page is the usual header and footer.
+    pub fn parse(html: &str) -> Verdict {
+        let article = Regex::new(r"(?s)<article[^>]*>(.*?)</article>").unwrap();
+        let article = article
+            .captures(html)
+            .map_or(html, |caps| caps.get(1).unwrap().as_str());
+        let tags = Regex::new(r"<[^>]*>").unwrap();
+        let text = tags
+            .replace_all(article, "")
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        if text.contains("That's the right answer") {
+            Verdict::Correct
+        } else if text.contains("That's not the right answer") {
+            if text.contains("too high") {
+                Verdict::TooHigh
+            } else if text.contains("too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Wrong
+            }
+        } else if text.contains("You gave an answer too recently") {
+            let wait = Regex::new(r"You have (.+?) left to wait").unwrap();
+            Verdict::RateLimited {
+                wait: wait.captures(&text).map(|caps| caps[1].to_string()),
+            }
+        } else if text.contains("You don't seem to be solving the right level") {
+            Verdict::AlreadySolved
+        } else {
+            Verdict::Unrecognised(text)
+        }
+    }
+}
+
+/// Where a day's input came from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Fetched {
+    /// It was already in the inputs directory, so the site wasn't asked for it again.
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// The real input for `day` if it is already in `inputs_dir`. The empty files left by `aoc new`
+/// don't count.
+pub fn cached_input(inputs_dir: &Path, day: u8) -> Option<PathBuf> {
+    let path = input::input_path(inputs_dir, day, false);
+    let len = fs::metadata(&path).ok()?.len();
+    (len > 0).then_some(path)
+}
+
+/// Makes sure the real input for `day` is in `inputs_dir`, downloading it only if it isn't.
+/// Inputs never change, so once downloaded they are never downloaded again.
+pub fn fetch_input(client: &Client, inputs_dir: &Path, day: u8) -> Result<Fetched, ClientError> {
+    if let Some(path) = cached_input(inputs_dir, day) {
+        return Ok(Fetched::Cached(path));
+    }
+    let text = client.input(day)?;
+    fs::create_dir_all(inputs_dir)?;
+    let path = input::input_path(inputs_dir, day, false);
+    // written next to it first and renamed into place, since anything in `path` is taken to be
+    // the whole input from then on, and being interrupted part way through would leave half of it
+    let partial = path.with_extension("txt.partial");
+    fs::write(&partial, text)
+        .and_then(|()| fs::rename(&partial, &path))
+        .map_err(|err| {
+            let _ = fs::remove_file(&partial);
+            io::Error::new(err.kind(), format!("{}: {err}", path.display()))
+        })?;
+    Ok(Fetched::Downloaded(path))
+}
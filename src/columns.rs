@@ -0,0 +1,185 @@
+//! Tables of numbers read column by column, like day 01's two lists of location IDs, and ways of
+//! measuring how far apart two of the columns are.
+
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+use crate::error::{self, ParseError};
+
+/// Numbers in columns, where every row has the same number of them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Table<T> {
+    columns: Vec<Vec<T>>,
+}
+
+impl<T: FromStr> Table<T> {
+    /// Parses whitespace separated numbers, one row per line, skipping blank lines. Every row
+    /// needs `width` numbers if given, or as many as the first row otherwise.
+    pub fn parse(input: &str, width: Option<usize>) -> Result<Table<T>, ParseError> {
+        let mut columns: Vec<Vec<T>> = Vec::new();
+        for (line_no, line) in error::numbered_lines(input) {
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+            if tokens.is_empty() {
+                continue;
+            }
+            let expected = width.unwrap_or(if columns.is_empty() {
+                tokens.len()
+            } else {
+                columns.len()
+            });
+            if tokens.len() != expected {
+                let like = if width.is_some() {
+                    ""
+                } else {
+                    " like the first line"
+                };
+                return Err(ParseError::new(
+                    line_no,
+                    format!("expected {expected} numbers{like}, found {}", tokens.len()),
+                ));
+            }
+            columns.resize_with(expected, Vec::new);
+            for (column, token) in columns.iter_mut().zip(tokens) {
+                column.push(error::parse_number(line_no, line, token)?);
+            }
+        }
+        Ok(Table { columns })
+    }
+}
+
+impl<T> Table<T> {
+    /// How many columns there are, or 0 for an empty table.
+    pub fn width(&self) -> usize {
+        self.columns.len()
+    }
+
+    pub fn rows(&self) -> usize {
+        self.columns.first().map_or(0, Vec::len)
+    }
+
+    pub fn column(&self, i: usize) -> &[T] {
+        &self.columns[i]
+    }
+
+    pub fn into_columns(self) -> Vec<Vec<T>> {
+        self.columns
+    }
+}
+
+/// The distance between two columns, as worked out by [`Table::pairwise`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Pairwise {
+    pub left: usize,
+    pub right: usize,
+    pub distance: f64,
+}
+
+impl<T: Copy + Into<f64>> Table<T> {
+    /// `metric`'s distance between every pair of columns, the left one first, e.g. (0, 1),
+    /// (0, 2) then (1, 2) for three columns.
+    pub fn pairwise(&self, metric: &dyn Metric) -> Vec<Pairwise> {
+        let columns: Vec<Vec<f64>> = self
+            .columns
+            .iter()
+            .map(|column| column.iter().map(|&n| n.into()).collect())
+            .collect();
+        let mut distances = Vec::new();
+        for (left, a) in columns.iter().enumerate() {
+            for (right, b) in columns.iter().enumerate().skip(left + 1) {
+                distances.push(Pairwise {
+                    left,
+                    right,
+                    distance: metric.distance(a, b),
+                });
+            }
+        }
+        distances
+    }
+}
+
+/// A way of saying how far apart two columns of the same length are. Columns are handed over in
+/// their original row order, so it's up to the metric whether that matters.
+pub trait Metric {
+    fn distance(&self, a: &[f64], b: &[f64]) -> f64;
+}
+
+/// The column sorted, so that the smallest numbers are paired up, then the next smallest and so
+/// on, which is how day 01 reconciles its lists.
+fn sorted(column: &[f64]) -> Vec<f64> {
+    let mut sorted = column.to_vec();
+    sorted.sort_unstable_by(f64::total_cmp);
+    sorted
+}
+
+fn sorted_diffs(a: &[f64], b: &[f64]) -> impl Iterator<Item = f64> {
+    sorted(a)
+        .into_iter()
+        .zip(sorted(b))
+        .map(|(x, y)| (x - y).abs())
+}
+
+/// The sum of the differences between the sorted columns, i.e. day 01's part one.
+pub struct L1;
+
+impl Metric for L1 {
+    fn distance(&self, a: &[f64], b: &[f64]) -> f64 {
+        sorted_diffs(a, b).sum()
+    }
+}
+
+/// The Euclidean distance between the sorted columns, which makes more of a few big differences
+/// than of many small ones.
+pub struct L2;
+
+impl Metric for L2 {
+    fn distance(&self, a: &[f64], b: &[f64]) -> f64 {
+        sorted_diffs(a, b).map(|d| d * d).sum::<f64>().sqrt()
+    }
+}
+
+/// The biggest difference between the sorted columns.
+pub struct LInf;
+
+impl Metric for LInf {
+    fn distance(&self, a: &[f64], b: &[f64]) -> f64 {
+        sorted_diffs(a, b).fold(0.0, f64::max)
+    }
+}
+
+/// How differently the two columns order the rows, rather than how far apart their numbers are:
+/// the sum over every row of how far its rank in one column is from its rank in the other
+/// (Spearman's footrule). Columns in the same order are 0 apart, however different the numbers.
+pub struct Rank;
+
+impl Metric for Rank {
+    fn distance(&self, a: &[f64], b: &[f64]) -> f64 {
+        ranks(a)
+            .into_iter()
+            .zip(ranks(b))
+            .map(|(x, y)| (x - y).abs())
+            .sum()
+    }
+}
+
+/// The rank of each number in its column, counting from 0, where equal numbers share the average
+/// of the ranks they would have had, so that the order of ties doesn't matter.
+fn ranks(column: &[f64]) -> Vec<f64> {
+    let mut order: Vec<usize> = (0..column.len()).collect();
+    order.sort_by(|&i, &j| column[i].total_cmp(&column[j]));
+    let mut ranks = vec![0.0; column.len()];
+    let mut start = 0;
+    while start < order.len() {
+        let mut end = start + 1;
+        while end < order.len()
+            && column[order[end]].total_cmp(&column[order[start]]) == Ordering::Equal
+        {
+            end += 1;
+        }
+        let rank = (start + end - 1) as f64 / 2.0;
+        for &row in &order[start..end] {
+            ranks[row] = rank;
+        }
+        start = end;
+    }
+    ranks
+}
@@ -0,0 +1,291 @@
+//! # Commentary
+//!
+//! This was pretty straightforward.
+//!
+//! # Things I tried and learnt
+//!
+//! One thing I did deliberate on was how best to initialise the HashMap with defaults.
+//! The recommended way to use the Rust Entry API is to chain .and_modify() before .or_insert(),
+//! like so:
+//! `entry(ch).and_modify(|counter| *counter += 1).or_insert(1);`
+//! However, I still prefer the logical flow prevalent other languages (Java, Python) where we
+//! 1) try to get the entry from the HashMap, failing which, insert a default entry,
+//! 2) update said entry.
+//! I decided while solving the challenge that this would be my preferred logical flow and stuck to
+//! it. It will be my default pattern until I understand the merits of the other way.
+//! TODO examine the pros/cons of why Rust Entry API prescribes the first way.
+//!
+//! The second thing was learning the difference between .sort() and .sort_unstable().
+//! In general, .sort_unstable() will be faster and guaranteed in-place (i.e. does not allocate
+//! auxiliary memory) than .sort(), with the trade off that it may reorder equal elements. This
+//! happens because different algorithms are used under the hood. As you might have guessed,
+//! .sort() uses a combination of MergeSort and QuickSort, with the former always needing extra
+//! memory. Whereas, .sort_unstable() uses only in-place algorithms, namely QuickSort and HeapSort
+//! for ver 1.83.
+//!
+//! UPDATE: the IDs are five-digit numbers, so there's no need for a comparison sort or a HashMap
+//! at all. When the IDs are packed densely enough, i.e. there are no more than a few possible IDs
+//! per row, the lists are counting sorted and part two looks the counts up in a plain array.
+//! Otherwise they're radix sorted a byte at a time, and part two walks both sorted lists together.
+//! On a generated input of a million rows (`aoc bench 1 --generate 1000000`), against a baseline
+//! of the `sort_unstable` and HashMap version, which is kept below as an alternative (both
+//! summing into a u64, since part two's answer no longer fits in a u32 at that size):
+//!
+//! | stage  | before  | after  |
+//! |--------|---------|--------|
+//! | parse  | 125.0ms | 98.0ms |
+//! | part 2 | 33.0ms  | 3.5ms  |
+//!
+//! Most of what's left of parsing is reading the text, not sorting. The real input has only a
+//! thousand rows, spread over 90,000 possible IDs, so it takes the radix sort and the merge-walk.
+//! Sorting made no difference at that size, but part two went from 65µs to 5µs, since walking
+//! two sorted lists is much cheaper than hashing every ID.
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::io::{self, BufRead};
+
+use crate::columns::Table;
+use crate::error::{self, ParseError};
+use crate::runner::Part;
+use crate::{Alternative, Solution};
+
+pub struct Day01;
+
+// UPDATE: the parsing now lives in `columns::Table`, which takes any number of columns, so that
+// the same reconciling can be done on other lists, see the metrics there
+fn build_cols(input: &str) -> Result<(Vec<u32>, Vec<u32>), ParseError> {
+    let table: Table<u32> = Table::parse(input, Some(2))?;
+    let mut columns = table.into_columns().into_iter();
+    Ok((
+        columns.next().unwrap_or_default(),
+        columns.next().unwrap_or_default(),
+    ))
+}
+
+// this is the L1 distance between the sorted lists, see `columns::L1`, summed as a u64 to match
+// part two
+fn solve_part_one(left: &[u32], right: &[u32]) -> u64 {
+    left.iter()
+        // we can pass a slice directly to .zip() since it implements IntoIterator
+        .zip(right)
+        .map(|(&l, &r)| l.abs_diff(r) as u64)
+        .sum()
+}
+
+/// The lists are dense if there are at most this many possible IDs per ID in the list, in which
+/// case an array with a slot for each possible ID is small enough to be worth it.
+const DENSE_IDS_PER_ROW: usize = 4;
+
+/// The smallest and largest IDs, and whether there are few enough possible IDs in between for an
+/// array with a slot for each of them.
+fn id_range(ids: &[u32]) -> Option<(u32, u32, bool)> {
+    let min = *ids.iter().min()?;
+    let max = *ids.iter().max()?;
+    let dense = (max - min) as usize / DENSE_IDS_PER_ROW < ids.len();
+    Some((min, max, dense))
+}
+
+/// Sorts the IDs with a counting sort if they're dense, or a radix sort otherwise.
+fn sort_ids(ids: &mut [u32]) {
+    match id_range(ids) {
+        Some((min, max, true)) => counting_sort(ids, min, max),
+        Some((min, max, false)) => radix_sort(ids, min, max),
+        None => {}
+    }
+}
+
+fn counting_sort(ids: &mut [u32], min: u32, max: u32) {
+    let mut counts = vec![0usize; (max - min) as usize + 1];
+    for &id in ids.iter() {
+        counts[(id - min) as usize] += 1;
+    }
+    let mut slots = ids.iter_mut();
+    for (offset, &count) in counts.iter().enumerate() {
+        for slot in slots.by_ref().take(count) {
+            *slot = min + offset as u32;
+        }
+    }
+}
+
+/// Least significant digit first radix sort, a byte at a time, of the IDs' offsets from `min`,
+/// which for five-digit IDs needs 3 passes rather than 4.
+fn radix_sort(ids: &mut [u32], min: u32, max: u32) {
+    let passes = (u32::BITS - (max - min).leading_zeros()).div_ceil(8);
+    let mut buffer = vec![0; ids.len()];
+    let (mut from, mut to): (&mut [u32], &mut [u32]) = (ids, &mut buffer);
+    for pass in 0..passes {
+        let digit = |id: u32| ((id - min) >> (pass * 8)) as usize & 0xff;
+        let mut starts = [0usize; 256];
+        for &id in from.iter() {
+            starts[digit(id)] += 1;
+        }
+        let mut total = 0;
+        for start in starts.iter_mut() {
+            (*start, total) = (total, total + *start);
+        }
+        for &id in from.iter() {
+            let start = &mut starts[digit(id)];
+            to[*start] = id;
+            *start += 1;
+        }
+        (from, to) = (to, from);
+    }
+    // after an odd number of passes, the sorted IDs are in the buffer
+    if passes % 2 == 1 {
+        to.copy_from_slice(from);
+    }
+}
+
+/// Part two over the sorted lists, looking up how often each ID is in the right list in an array
+/// if the IDs are dense, or by walking both lists together otherwise. The score is a u64, since a
+/// generated input of 100,000 rows is enough to overflow a u32.
+fn solve_part_two(left: &[u32], right: &[u32]) -> u64 {
+    match id_range(right) {
+        Some((min, max, true)) => similarity_dense(left, right, min, max),
+        Some(_) => similarity_merge(left, right),
+        None => 0,
+    }
+}
+
+fn similarity_dense(left: &[u32], right: &[u32], min: u32, max: u32) -> u64 {
+    let mut counts = vec![0u64; (max - min) as usize + 1];
+    for &id in right {
+        counts[(id - min) as usize] += 1;
+    }
+    left.iter()
+        .filter(|&&id| (min..=max).contains(&id))
+        .map(|&id| id as u64 * counts[(id - min) as usize])
+        .sum()
+}
+
+/// Both lists must be sorted: each run of equal IDs in the left list is matched up with the run
+/// of the same ID in the right list, if there is one.
+fn similarity_merge(left: &[u32], right: &[u32]) -> u64 {
+    let run = |ids: &[u32], start: usize| ids[start..].partition_point(|&id| id == ids[start]);
+    let (mut l, mut r) = (0, 0);
+    let mut sum = 0;
+    while l < left.len() && r < right.len() {
+        match left[l].cmp(&right[r]) {
+            Ordering::Less => l += 1,
+            Ordering::Greater => r += 1,
+            Ordering::Equal => {
+                let (left_run, right_run) = (run(left, l), run(right, r));
+                sum += left[l] as u64 * (left_run * right_run) as u64;
+                l += left_run;
+                r += right_run;
+            }
+        }
+    }
+    sum
+}
+
+// the original part two, which didn't need the lists sorted
+fn solve_part_two_hashmap(left: &[u32], right: &[u32]) -> u64 {
+    let mut hashmap: HashMap<u32, u64> = HashMap::new();
+    for &num in right {
+        let count = hashmap.entry(num).or_insert(0);
+        *count += 1;
+    }
+    left.iter().fold(0, |sum, &num| {
+        // .get() takes a ref and returns Option<&V>, we need to call .cloned() to get Option<V>
+        let count: u64 = hashmap.get(&num).cloned().unwrap_or(0);
+        sum + count * num as u64
+    })
+}
+
+/// Part two's similarity score, read a line at a time from `reader` rather than from the whole
+/// input in memory, for lists too big to load. Only how often each ID is in each list is kept, so
+/// memory is bounded by the number of distinct IDs, however many rows there are.
+///
+/// Counting the left list too means a single pass over the rows is enough, so this works on
+/// stdin, which can't be read twice. A line which isn't two numbers is an
+/// [`io::ErrorKind::InvalidData`] error wrapping the [`ParseError`].
+pub fn similarity_streaming(mut reader: impl BufRead) -> io::Result<u64> {
+    let mut left: HashMap<u32, u64> = HashMap::new();
+    let mut right: HashMap<u32, u64> = HashMap::new();
+    let mut line = String::new();
+    let mut line_no = 0;
+    while reader.read_line(&mut line)? > 0 {
+        line_no += 1;
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        match tokens[..] {
+            [] => {}
+            [l, r] => {
+                let invalid = |err| io::Error::new(io::ErrorKind::InvalidData, err);
+                let l = error::parse_number(line_no, &line, l).map_err(invalid)?;
+                let r = error::parse_number(line_no, &line, r).map_err(invalid)?;
+                *left.entry(l).or_insert(0) += 1;
+                *right.entry(r).or_insert(0) += 1;
+            }
+            _ => {
+                let message = format!("expected 2 numbers, found {}", tokens.len());
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    ParseError::new(line_no, message),
+                ));
+            }
+        }
+        line.clear();
+    }
+
+    Ok(left
+        .iter()
+        .map(|(&id, &count)| id as u64 * count * right.get(&id).copied().unwrap_or(0))
+        .sum())
+}
+
+fn part_one_sort_unstable(input: &str) -> Result<String, ParseError> {
+    let (mut left, mut right) = build_cols(input)?;
+    left.sort_unstable();
+    right.sort_unstable();
+    Ok(solve_part_one(&left, &right).to_string())
+}
+
+fn part_two_hashmap(input: &str) -> Result<String, ParseError> {
+    let (left, right) = build_cols(input)?;
+    Ok(solve_part_two_hashmap(&left, &right).to_string())
+}
+
+impl Solution for Day01 {
+    type Input = (Vec<u32>, Vec<u32>);
+    type Answer = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let (mut left, mut right) = build_cols(input)?;
+
+        // Sorting is necessary for part one and helps for part two, so we can do it here. Use
+        // .sort_unstable() which is usually faster and guaranteed in-place (i.e. will never allocate
+        // auxiliary memory) compared to .sort(). In this case, we don't care that it there is no
+        // stable order to equal elements.
+        //
+        // UPDATE: the lists are now counting or radix sorted, see `sort_ids`, and the
+        // .sort_unstable() version is kept as an alternative
+        sort_ids(&mut left);
+        sort_ids(&mut right);
+        Ok((left, right))
+    }
+
+    fn part_one((left, right): &Self::Input) -> u64 {
+        solve_part_one(left, right)
+    }
+
+    fn part_two((left, right): &Self::Input) -> u64 {
+        solve_part_two(left, right)
+    }
+
+    const ALTERNATIVES: &'static [Alternative] = &[
+        Alternative {
+            name: "sort_unstable",
+            part: Part::One,
+            solve: part_one_sort_unstable,
+            known_wrong: false,
+        },
+        Alternative {
+            name: "solve_part_two_hashmap",
+            part: Part::Two,
+            solve: part_two_hashmap,
+            known_wrong: false,
+        },
+    ];
+}
@@ -0,0 +1,390 @@
+//! # Commentary
+//!
+//! Part One was fairly straightforward, but I got into trouble trying to refactor my fn for use in
+//! part Two. Part One was basically a sliding window (size 2) over all elements, to see if any
+//! window violated the rules. It could be easily solved using constructs like .any() or .all(),
+//! which I initially wrote. However, I got carried away in Part Two which called for a tolerance
+//! of one element being in violation of the rules. I tried to reason that this meant having 1
+//! window being in violation, tested some inputs, but failed to realise an important edge case:
+//! there could be an input with 1 invalid window, that would still *remain unsafe* even after the
+//! removal of 1 element. As an example:
+//! [1, 2, 9, 10, 11, 12] -> even though the [2, 9] window is invalid, removing 9 still would not
+//! help as it would merely leave another [2, 10] invalid window.
+//!
+//! I was forced to abandon the "tolerance" approach and revert to the most straightforward idea
+//! of testing every sub-array with one element removed. You can see the mess I left, and still
+//! leave for posterity's sake, in the `is_safe_with_tolerance` fn.
+//!
+//! UPDATE: the tolerance idea is now registered as a known-wrong alternative for part two, and the
+//! differential test found an even simpler input it gets wrong: any safe report of just two
+//! levels, e.g. [48, 47], since a tolerance of one lets the only window count as both increasing
+//! and decreasing, and the XOR then calls it unsafe.
+//!
+//! UPDATE: part two no longer tries every report with each level taken out in turn, which clones
+//! the report for every level, see `is_safe_with_removals`. Instead it works out, for each level,
+//! the fewest levels that have to go for the report to be safe up to there, given that level is
+//! kept. Only the last k + 1 levels can come before it with at most k removals, so this is
+//! O(n·k), and works for any k rather than just the one the dampener allows. The brute force is
+//! kept as an alternative to check it against.
+//!
+//! UPDATE: the rules themselves, i.e. how big a step can be, whether the levels have to keep going
+//! the same way, and how many levels can be taken out, are now a `SafetyPolicy`, with one for
+//! each part. A policy can also explain why a report isn't safe, which `aoc reports` prints for
+//! every report, rather than just counting them.
+//!
+//! # Things I tried and learnt
+//! - Using the XOR operator to combine 2 mutually exclusive flags, which may help to ferret out
+//! strange bugs if the flags are not correctly set, as opposed to using the OR operator.
+//!
+//! - Using the .windows() method on the Slice type. Which will work for anything "slice-able",
+//! like arrays and Vecs. It makes it much much easier to generate all possible windows, without
+//! worrying about going out of bounds etc.  
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::RangeInclusive;
+
+use crate::error::{self, ParseError, Violation};
+use crate::runner::Part;
+use crate::{Alternative, Solution};
+
+pub struct Day02;
+
+fn parse_input(input: &str) -> Result<Vec<Vec<u32>>, ParseError> {
+    Ok(parse_numbered(input)?
+        .into_iter()
+        .map(|(_, row)| row)
+        .collect())
+}
+
+// Each report along with the line it's on, since blank lines are skipped
+fn parse_numbered(input: &str) -> Result<Vec<(usize, Vec<u32>)>, ParseError> {
+    error::numbered_lines(input)
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(line_no, line)| {
+            let row = line
+                .split_whitespace()
+                .map(|s| error::parse_number::<u32>(line_no, line, s))
+                .collect::<Result<_, _>>()?;
+            Ok((line_no, row))
+        })
+        .collect()
+}
+
+// A single level is neither increasing nor decreasing, and taking one out with the dampener would
+// leave nothing at all to check, so every report needs at least two
+fn validate_input(input: &str) -> Vec<Violation> {
+    error::numbered_lines(input)
+        .filter(|(_, line)| line.split_whitespace().count() == 1)
+        .map(|(line_no, _)| {
+            Violation::on_line(line_no, "a report should have at least two levels, not one")
+        })
+        .collect()
+}
+
+// Note: This function was refactored into this strange state as I thought it could be repurposed
+// to use with part two, but having a tolerance of 1 invalid window within windows. Unfortunately,
+// this failed to consider the edge case where having 1 invalid window would still be unsafe, as
+// in
+fn is_safe_with_tolerance(row: &[u32], tolerance: usize) -> bool {
+    let num_windows = row.len() - 1;
+    let num_decreasing = row.windows(2).filter(|w| w[0] > w[1]).count();
+    let num_increasing = row.windows(2).filter(|w| w[1] > w[0]).count();
+    let num_in_range = row
+        .windows(2)
+        .map(|w| w[0].abs_diff(w[1]))
+        .filter(|diff| (1..4).contains(diff)) // 1-3 inclusive
+        .count();
+
+    let is_decreasing = num_windows - num_decreasing <= tolerance;
+    let is_increasing = num_windows - num_increasing <= tolerance;
+    let is_in_range = num_windows - num_in_range <= tolerance;
+    // use XOR as more semantically correct, even though both cannot be true
+    is_decreasing ^ is_increasing && is_in_range
+}
+
+// The original part two, kept as the reference for `is_safe_with_removals`
+pub fn is_safe_with_dampener(row: &[u32]) -> bool {
+    if is_safe_with_tolerance(row, 0) {
+        return true;
+    }
+
+    for i in 0..row.len() {
+        let mut modified = row.to_vec();
+        modified.remove(i);
+        if is_safe_with_tolerance(&modified, 0) {
+            return true;
+        }
+    }
+    false
+}
+
+/// Whether the report can be made safe by taking out at most `k` of its levels, as long as at
+/// least two are left. The dampener is a `k` of 1.
+pub fn is_safe_with_removals(row: &[u32], k: usize) -> bool {
+    SafetyPolicy {
+        tolerance: k,
+        ..SafetyPolicy::PART_ONE
+    }
+    .is_safe(row)
+}
+
+/// The rules a report has to follow to be safe.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SafetyPolicy {
+    /// How far apart neighbouring levels can be, e.g. 1 to 3, which rules out equal levels.
+    pub steps: RangeInclusive<u32>,
+    /// Whether the levels can go up and down, rather than only ever one way.
+    pub direction_can_change: bool,
+    /// How many levels can be taken out to make a report safe, as long as at least two are left.
+    pub tolerance: usize,
+}
+
+impl SafetyPolicy {
+    pub const PART_ONE: SafetyPolicy = SafetyPolicy {
+        steps: 1..=3,
+        direction_can_change: false,
+        tolerance: 0,
+    };
+
+    /// Part one's rules, with the dampener taking out one level.
+    pub const PART_TWO: SafetyPolicy = SafetyPolicy {
+        tolerance: 1,
+        ..SafetyPolicy::PART_ONE
+    };
+
+    pub fn is_safe(&self, row: &[u32]) -> bool {
+        let k = self.tolerance;
+        // most reports are safe as they are, which one pass over the levels can tell
+        if self.first_violation(row.iter().copied()).is_none() {
+            return true;
+        }
+        if k == 0 {
+            return false;
+        }
+        if self.direction_can_change {
+            return fewest_removals(row, k, |a, b| self.steps.contains(&a.abs_diff(b))) <= k;
+        }
+        let increasing = |a: u32, b: u32| b >= a && self.steps.contains(&(b - a));
+        let decreasing = |a: u32, b: u32| increasing(b, a);
+        fewest_removals(row, k, increasing) <= k || fewest_removals(row, k, decreasing) <= k
+    }
+
+    /// Why the report breaks the rules before taking any levels out, or `None` if it doesn't.
+    pub fn diagnose(&self, row: &[u32]) -> Option<Diagnosis> {
+        let (window, rule) = self.first_violation(row.iter().copied())?;
+        // checking each level by skipping it rather than copying the report without it
+        let fix = (0..row.len()).find(|&i| {
+            let without = row.iter().enumerate().filter(|&(j, _)| j != i);
+            self.first_violation(without.map(|(_, &level)| level))
+                .is_none()
+        });
+        Some(Diagnosis {
+            window,
+            rule,
+            fix,
+            tolerance: self.tolerance,
+            saved: self.is_safe(row),
+        })
+    }
+
+    /// The first window of two levels which breaks a rule, as the index of its first level, and
+    /// the rule it breaks. Levels going up then staying level still count as going up.
+    fn first_violation(&self, levels: impl Iterator<Item = u32>) -> Option<(usize, Rule)> {
+        let mut direction = Ordering::Equal;
+        let mut prev = None;
+        let mut count = 0;
+        for (i, level) in levels.enumerate() {
+            count += 1;
+            let Some(from) = prev.replace(level) else {
+                continue;
+            };
+            let to = level;
+            if !self.steps.contains(&from.abs_diff(to)) {
+                return Some((i - 1, Rule::Step { from, to }));
+            }
+            match (direction, to.cmp(&from)) {
+                (_, Ordering::Equal) => {}
+                (Ordering::Equal, way) => direction = way,
+                (way, new_way) if way != new_way && !self.direction_can_change => {
+                    return Some((i - 1, Rule::DirectionChanged { from, to }));
+                }
+                _ => {}
+            }
+        }
+        (count < 2).then_some((0, Rule::TooFewLevels))
+    }
+}
+
+/// One of the rules in a [`SafetyPolicy`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Rule {
+    /// A step from one level to the next outside the allowed steps.
+    Step { from: u32, to: u32 },
+    /// A step the other way from the ones before it.
+    DirectionChanged { from: u32, to: u32 },
+    /// Fewer than two levels, which go neither up nor down.
+    TooFewLevels,
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Rule::Step { from, to } => {
+                write!(f, "{from} to {to} is a step of {}", from.abs_diff(to))
+            }
+            Rule::DirectionChanged { from, to } => {
+                let way = if to > from { "up" } else { "down" };
+                write!(f, "{from} to {to} changes direction to go {way}")
+            }
+            Rule::TooFewLevels => write!(f, "fewer than two levels"),
+        }
+    }
+}
+
+/// Why a report isn't safe as it is.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Diagnosis {
+    /// Index of the first level of the first window of two levels that breaks a rule.
+    pub window: usize,
+    pub rule: Rule,
+    /// Index of the first level which, taken out, leaves a safe report, if there is one.
+    pub fix: Option<usize>,
+    /// How many levels the policy lets be taken out.
+    pub tolerance: usize,
+    /// Whether taking out at most `tolerance` levels makes the report safe.
+    pub saved: bool,
+}
+
+impl fmt::Display for Diagnosis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // levels are counted from 1, like lines and columns
+        if self.rule != Rule::TooFewLevels {
+            write!(f, "levels {} and {}: ", self.window + 1, self.window + 2)?;
+        }
+        write!(f, "{}", self.rule)?;
+        match (self.fix, self.tolerance) {
+            (Some(i), 0) => write!(
+                f,
+                ", taking out level {} would fix it, but no levels can be taken out",
+                i + 1
+            ),
+            (Some(i), _) => write!(f, ", taking out level {} fixes it", i + 1),
+            (None, 0) => Ok(()),
+            (None, 1) => write!(f, ", taking out one level doesn't fix it"),
+            (None, k) if self.saved => write!(
+                f,
+                ", no one level fixes it, but taking out up to {k} levels does"
+            ),
+            (None, k) => write!(f, ", taking out up to {k} levels doesn't fix it"),
+        }
+    }
+}
+
+/// Every report in the input, whether it's safe under `policy`, and why it isn't safe as it is.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Explanation {
+    pub line: usize,
+    pub safe: bool,
+    pub diagnosis: Option<Diagnosis>,
+}
+
+pub fn explain(input: &str, policy: &SafetyPolicy) -> Result<Vec<Explanation>, ParseError> {
+    Ok(parse_numbered(input)?
+        .into_iter()
+        .map(|(line, row)| Explanation {
+            line,
+            safe: policy.is_safe(&row),
+            diagnosis: policy.diagnose(&row),
+        })
+        .collect())
+}
+
+/// The fewest levels to take out of `row` so that every pair of levels left next to each other
+/// is `allowed`, and at least two are left, or more than `k` if it can't be done in `k`.
+fn fewest_removals(row: &[u32], k: usize, allowed: impl Fn(u32, u32) -> bool) -> usize {
+    let n = row.len();
+    // chained[i] is the fewest removals before level i such that it's kept, and is the end of a
+    // safe run of at least two levels; a run of just level i needs all i levels before it taken
+    // out
+    let mut chained = vec![usize::MAX; n];
+    let mut fewest = usize::MAX;
+    for i in 0..n {
+        for j in i.saturating_sub(k + 1)..i {
+            if allowed(row[j], row[i]) {
+                let before = chained[j].min(j);
+                chained[i] = chained[i].min(before + (i - j - 1));
+            }
+        }
+        fewest = fewest.min(chained[i].saturating_add(n - 1 - i));
+    }
+    fewest
+}
+
+fn solve_part_one(input: &[Vec<u32>]) -> u32 {
+    input
+        .iter()
+        .filter(|row| SafetyPolicy::PART_ONE.is_safe(row))
+        .count() as u32
+}
+
+fn solve_part_two(input: &[Vec<u32>]) -> u32 {
+    input
+        .iter()
+        .filter(|row| SafetyPolicy::PART_TWO.is_safe(row))
+        .count() as u32
+}
+
+fn part_two_brute_force(input: &str) -> Result<String, ParseError> {
+    let safe = parse_input(input)?
+        .iter()
+        .filter(|row| is_safe_with_dampener(row))
+        .count();
+    Ok(safe.to_string())
+}
+
+// The abandoned idea for part two, kept as a known-wrong alternative so the differential test can
+// dig up an input like the one above for it
+fn part_two_with_tolerance(input: &str) -> Result<String, ParseError> {
+    let safe = parse_input(input)?
+        .iter()
+        .filter(|row| is_safe_with_tolerance(row, 1))
+        .count();
+    Ok(safe.to_string())
+}
+
+impl Solution for Day02 {
+    type Input = Vec<Vec<u32>>;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> u32 {
+        solve_part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> u32 {
+        solve_part_two(input)
+    }
+
+    fn validate(input: &str) -> Vec<Violation> {
+        validate_input(input)
+    }
+
+    const ALTERNATIVES: &'static [Alternative] = &[
+        Alternative {
+            name: "is_safe_with_tolerance",
+            part: Part::Two,
+            solve: part_two_with_tolerance,
+            known_wrong: true,
+        },
+        Alternative {
+            name: "is_safe_with_dampener",
+            part: Part::Two,
+            solve: part_two_brute_force,
+            known_wrong: false,
+        },
+    ];
+}
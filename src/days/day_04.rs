@@ -0,0 +1,151 @@
+//! Commentary
+//!
+//! This became an excuse for me to practice my DFS. With the result being an over-engineered
+//! crossword word finder.
+
+use crate::error::ParseError;
+use crate::grid::{self, Grid};
+use crate::render::{Color, Layer};
+use crate::Solution;
+
+pub struct Day04;
+
+fn parse_input(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(input)
+}
+
+// Every XMAS, as the cell its X is on and the direction it reads in
+// UPDATE: this used to just count them, but `aoc render` wants to know where they are
+fn xmas_hits(grid: &Grid<char>) -> Vec<((usize, usize), (isize, isize))> {
+    let mut hits = Vec::new();
+    let target_s = String::from("XMAS");
+
+    for row in 0..grid.rows() {
+        for col in 0..grid.cols() {
+            for direction in grid::ALL_DIRECTIONS {
+                // subtle point - technically we should implement backtracking properly,
+                // but just being lazy and using a brand new string for each exploration
+                // since backtracking only needed for the initial grid cell, TODO clean
+                let mut path_s = String::with_capacity(target_s.len());
+                if dfs_match_str(grid, direction, &mut path_s, &target_s, (row, col)) {
+                    hits.push(((row, col), direction));
+                }
+            }
+        }
+    }
+    hits
+}
+
+fn solve_part_one(grid: &Grid<char>) -> u32 {
+    xmas_hits(grid).len() as u32
+}
+
+fn dfs_match_str(
+    grid: &Grid<char>,
+    nxt_dir: (isize, isize),
+    path_s: &mut String,
+    target_s: &str,
+    cell: (usize, usize),
+) -> bool {
+    path_s.push(grid[cell]); // push current char
+    if !target_s.starts_with(&path_s[..]) {
+        false // no match, terminate
+    } else if path_s == target_s {
+        true // match found
+    } else {
+        // UPDATE: the bounds checking (and the casting to i32 it needed) now lives in Grid
+        match grid.offset(cell, nxt_dir) {
+            Some(next_cell) => dfs_match_str(grid, nxt_dir, path_s, target_s, next_cell),
+            None => false,
+        }
+    }
+}
+
+// the centre of every X-MAS, i.e. the A
+fn x_mas_centres(grid: &Grid<char>) -> Vec<(usize, usize)> {
+    let mut centres = Vec::new();
+    fn is_pair(c1: char, c2: char) -> bool {
+        c1 == 'M' && c2 == 'S' || c1 == 'S' && c2 == 'M'
+    }
+
+    // exclude the boundary rows and cols to make things easier, saturating so that a grid too
+    // small to have any centres (or an empty one) doesn't underflow
+    for row in 1..grid.rows().saturating_sub(1) {
+        for col in 1..grid.cols().saturating_sub(1) {
+            if grid[(row, col)] == 'A' {
+                let nw = grid[(row - 1, col - 1)];
+                let sw = grid[(row + 1, col - 1)];
+                let ne = grid[(row - 1, col + 1)];
+                let se = grid[(row + 1, col + 1)];
+                if is_pair(nw, se) && is_pair(ne, sw) {
+                    centres.push((row, col));
+                }
+            }
+        }
+    }
+    centres
+}
+
+fn solve_part_two(grid: &Grid<char>) -> u32 {
+    x_mas_centres(grid).len() as u32
+}
+
+/// The letters of every XMAS, coloured by which way it reads, and every X-MAS on top.
+pub fn layers(grid: &Grid<char>) -> Vec<Layer> {
+    let mut xmas = Layer::new(
+        "xmas",
+        &[
+            Color::rgb(0xe6, 0x19, 0x4b),
+            Color::rgb(0xf5, 0x82, 0x31),
+            Color::rgb(0xff, 0xe1, 0x19),
+            Color::rgb(0x3c, 0xb4, 0x4b),
+            Color::rgb(0x42, 0xd4, 0xf4),
+            Color::rgb(0x43, 0x63, 0xd8),
+            Color::rgb(0x91, 0x1e, 0xb4),
+            Color::rgb(0xf0, 0x32, 0xe6),
+        ],
+    );
+    for (start, direction) in xmas_hits(grid) {
+        let shade = grid::ALL_DIRECTIONS
+            .iter()
+            .position(|&d| d == direction)
+            .unwrap_or_default();
+        let mut cell = Some(start);
+        for _ in 0.."XMAS".len() {
+            let Some(pos) = cell else { break };
+            xmas.mark(pos, shade);
+            cell = grid.offset(pos, direction);
+        }
+    }
+
+    let mut x_mas = Layer::new("x-mas", &[Color::rgb(0x80, 0x80, 0x00)]);
+    for (row, col) in x_mas_centres(grid) {
+        for pos in [
+            (row, col),
+            (row - 1, col - 1),
+            (row - 1, col + 1),
+            (row + 1, col - 1),
+            (row + 1, col + 1),
+        ] {
+            x_mas.mark(pos, 0);
+        }
+    }
+    vec![xmas, x_mas]
+}
+
+impl Solution for Day04 {
+    type Input = Grid<char>;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(grid: &Self::Input) -> u32 {
+        solve_part_one(grid)
+    }
+
+    fn part_two(grid: &Self::Input) -> u32 {
+        solve_part_two(grid)
+    }
+}
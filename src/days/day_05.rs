@@ -0,0 +1,213 @@
+//! Commentary
+//!
+//! Once again, I was overly eager to practice my DFS seeing that this was clearly could be a graph
+//! problem describing the relationships between pages and their prequisites. Before I could end up
+//! writing the Topological Sort though, I realised that there was much simpler way given the
+//! constraints of this graph.
+//! TBC
+//!
+//! Assumptions
+//! 1. There are no circular dependencies encoded in the rules of prereqs-to-targets (i.e. the
+//! graph is acyclic). Otherwise the list of rules is invalid and the whole problem falls apart. So
+//! we can skip this check.
+//! 2. All source preqrequisites are directly connected to their dependent destinations, because there
+//! is an explicit rule for every single preq-to-target relationship.
+//! TODO: it seems like the term for this might be a Transitive Closure, to check
+//! UPDATE: both are now checked by `validate_input`, and it turns out that the rules in the real
+//! input DO go round in circles when taken as a whole! Luckily, both assumptions only need to
+//! hold for the pages of each update, and there they do.
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use crate::error::{self, ParseError, Violation};
+use crate::Solution;
+
+pub struct Day05;
+
+// rules as an adjacency list of each page to the pages that must come after it, and the updates
+type Manual = (HashMap<u32, Vec<u32>>, Vec<Vec<u32>>);
+
+fn parse_input(input: &str) -> Result<Manual, ParseError> {
+    // UPDATED - much easier to break the input into a Vector of 2 strings
+    // an alternative is to use .take_while() to yield lines until the empty line
+    // then we can continue the iterator for the second part
+    // UPDATE 02 - splitting on "\n\n" fell over on CRLF files, and indexing the sections
+    // panicked when there was no blank line, so it's the alternative after all. The same
+    // iterator carries on into the second part, keeping the line numbers for the errors
+    let mut lines = error::numbered_lines(input);
+
+    let mut dep_adj_list: HashMap<u32, Vec<u32>> = HashMap::new();
+    let mut found_separator = false;
+    for (line_no, dep) in lines.by_ref() {
+        if dep.trim().is_empty() {
+            found_separator = true;
+            break;
+        }
+        let Some((source, dest)) = dep.split_once('|') else {
+            return Err(ParseError::new(line_no, "expected `a|b`"));
+        };
+        let source = error::parse_number::<u32>(line_no, dep, source)?;
+        let dest = error::parse_number::<u32>(line_no, dep, dest)?;
+        let entry = dep_adj_list.entry(source).or_default();
+        entry.push(dest);
+    }
+    if !found_separator {
+        let line_no = input.lines().count() + 1;
+        return Err(ParseError::new(
+            line_no,
+            "expected a blank line between the rules and the updates",
+        ));
+    }
+
+    let tasks = lines
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(line_no, line)| {
+            line.split(',')
+                .map(|s| error::parse_number::<u32>(line_no, line, s))
+                .collect()
+        })
+        .collect::<Result<Vec<Vec<u32>>, _>>()?;
+
+    Ok((dep_adj_list, tasks))
+}
+
+// Checks both assumptions for the pages of every update, reporting the first pair of pages in each
+// update which breaks them
+fn validate_input(input: &str) -> Vec<Violation> {
+    if parse_input(input).is_err() {
+        return Vec::new(); // parse has already said what's wrong
+    }
+    let mut lines = error::numbered_lines(input);
+    // which line each rule is on, to point at it
+    let mut rule_lines: HashMap<(u32, u32), usize> = HashMap::new();
+    for (line_no, line) in lines.by_ref() {
+        let Some((source, dest)) = line.split_once('|') else {
+            break; // the blank line
+        };
+        if let (Ok(source), Ok(dest)) = (source.parse(), dest.parse()) {
+            rule_lines.entry((source, dest)).or_insert(line_no);
+        }
+    }
+
+    let mut violations = Vec::new();
+    for (line_no, line) in lines.filter(|(_, line)| !line.trim().is_empty()) {
+        let pages: Vec<u32> = line.split(',').filter_map(|s| s.parse().ok()).collect();
+        if let Some(message) = update_violation(&pages, &rule_lines) {
+            violations.push(Violation::on_line(line_no, message));
+        }
+    }
+    violations
+}
+
+fn update_violation(pages: &[u32], rule_lines: &HashMap<(u32, u32), usize>) -> Option<String> {
+    for (i, &v) in pages.iter().enumerate() {
+        for &w in &pages[i + 1..] {
+            match (rule_lines.get(&(v, w)), rule_lines.get(&(w, v))) {
+                _ if v == w => return Some(format!("page {v} is in the update twice")),
+                (None, None) => return Some(format!("no rule says whether {v} or {w} comes first")),
+                (Some(line), Some(other_line)) => {
+                    return Some(format!(
+                        "the rules {v}|{w} on line {line} and {w}|{v} on line {other_line} contradict each other"
+                    ))
+                }
+                _ => {}
+            }
+        }
+    }
+    // every pair of pages has exactly one rule, so the only thing left to go wrong is a cycle,
+    // and if there is one, there is one of only three pages
+    let before = |v: u32, w: u32| rule_lines.contains_key(&(v, w));
+    for &a in pages {
+        for &b in pages.iter().filter(|&&b| before(a, b)) {
+            if let Some(&c) = pages.iter().find(|&&c| before(b, c) && before(c, a)) {
+                return Some(format!(
+                    "the rules {a}|{b}, {b}|{c} and {c}|{a} go round in a circle"
+                ));
+            }
+        }
+    }
+    None
+}
+
+fn is_valid_task_path(task_path: &[u32], dep_adj_list: &HashMap<u32, Vec<u32>>) -> bool {
+    // Because of assumption 2: each source will map directly to the subequent destination, we can
+    // save on checking the remaining nodes except the immediate the next node. Use sliding window.
+    for win in task_path.windows(2) {
+        let source = win[0];
+        let dest = win[1];
+        if let Some(known_destinations) = dep_adj_list.get(&source) {
+            if !known_destinations.contains(&dest) {
+                return false;
+            }
+        } else {
+            // source vertex has no destinations, the path is broken
+            return false;
+        }
+    }
+    true
+}
+
+fn to_reordered_task_path(task_path: &[u32], adj_list: &HashMap<u32, Vec<u32>>) -> Vec<u32> {
+    // Once again, because of assumption 2, saves us the trouble of performing full topological
+    // reordering of the path, e.g. using DFS. Instead, we know that between 2 nodes 'v' and 'w',
+    // 'v' must either appear in the (outgoing) adjacency list of 'w', or 'w' must appear in the
+    // adjacency list of 'v'. Hence, we can pass the result of this comparison to a sort function.
+    let mut reordered = task_path.to_vec();
+    reordered.sort_unstable_by(|v, w| {
+        let v_dests = adj_list.get(v);
+        let w_dests = adj_list.get(w);
+        if v_dests.is_some() && v_dests.unwrap().contains(w) {
+            Ordering::Less
+        } else if w_dests.is_some() && w_dests.unwrap().contains(v) {
+            Ordering::Greater
+        } else {
+            unreachable!();
+        }
+    });
+    reordered
+    /*
+    1. sort task_path, using the predicate fn for elements 'v' and 'w'
+    2. if 'v' in adj list of 'w', return Order::LessThan // v is prereq
+    3. elseif 'w' in adj list of 'v', return Order::GreaterThan
+    4. else unreachable!()
+    */
+}
+
+fn solve_part_one(dep_adj_list: &HashMap<u32, Vec<u32>>, tasks: &[Vec<u32>]) -> u32 {
+    tasks
+        .iter()
+        .filter(|task_path| is_valid_task_path(task_path, dep_adj_list))
+        .map(|task_path| task_path[task_path.len() / 2])
+        .sum()
+}
+
+fn solve_part_two(dep_adj_list: &HashMap<u32, Vec<u32>>, tasks: &[Vec<u32>]) -> u32 {
+    tasks
+        .iter()
+        .filter(|task_path| !is_valid_task_path(task_path, dep_adj_list))
+        .map(|task_path| to_reordered_task_path(task_path, dep_adj_list))
+        .map(|reordered| reordered[reordered.len() / 2])
+        .sum()
+}
+
+impl Solution for Day05 {
+    type Input = Manual;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one((dep_adj_list, tasks): &Self::Input) -> u32 {
+        solve_part_one(dep_adj_list, tasks)
+    }
+
+    fn part_two((dep_adj_list, tasks): &Self::Input) -> u32 {
+        solve_part_two(dep_adj_list, tasks)
+    }
+
+    fn validate(input: &str) -> Vec<Violation> {
+        validate_input(input)
+    }
+}
@@ -0,0 +1,403 @@
+//! Commentary
+//!
+//! Having seen so many Grid problems, I was frustrated at writing the same code over and over
+//! again and decided to create a reusable GridExplorer, and finally get some practice with
+//! writing structs.
+//!
+//! As part of an optimization to use the same grid in memory in every brute-force search, I was
+//! forced to take a ref to the grid and use dreaded lifetimes.
+//!
+//! UPDATE: both parts used to have their own copy of the walking loop, which is now
+//! `GridExplorer::step`, so that `aoc patrol` can show the guard walking exactly the way the
+//! solution does, one step at a time.
+
+use std::ops::ControlFlow;
+
+use crate::error::{ParseError, Violation};
+use crate::geometry::{Direction, Point};
+use crate::grid::Grid;
+use crate::render::{Color, Layer};
+use crate::Solution;
+
+pub struct Day06;
+
+// The explorer starts from the one and only guard, so a map without exactly one doesn't parse
+fn parse_input(input: &str) -> Result<Grid<char>, ParseError> {
+    let grid = Grid::parse(input)?;
+    let mut guards = grid.positions().filter(|&pos| grid[pos] == '^');
+    let Some((first_row, _)) = guards.next() else {
+        let line_no = input.lines().count() + 1;
+        return Err(ParseError::new(line_no, "there is no guard `^` on the map"));
+    };
+    if let Some((row, col)) = guards.next() {
+        return Err(ParseError::new(
+            row + 1,
+            format!(
+                "another guard `^`, besides the one on line {}",
+                first_row + 1
+            ),
+        )
+        .at_column(col + 1));
+    }
+    Ok(grid)
+}
+
+// The guard is assumed to be facing north, and to be the only thing on the map besides the
+// obstructions
+fn validate_input(input: &str) -> Vec<Violation> {
+    let Ok(grid) = parse_input(input) else {
+        return Vec::new(); // parse has already said what's wrong
+    };
+    let mut violations = Vec::new();
+    for ((row, col), &ch) in grid.iter() {
+        if !matches!(ch, '.' | '#' | '^') {
+            violations.push(Violation::on_line(
+                row + 1,
+                format!(
+                    "unexpected `{ch}` at column {}, the map should only have `.`, `#` and a `^`",
+                    col + 1
+                ),
+            ));
+        }
+    }
+    violations
+}
+
+/// What the guard did in one step of the patrol.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Step {
+    /// Stepped forward onto the next cell.
+    Moved,
+    /// Turned right, in front of an obstruction.
+    Turned,
+    /// Stepped off the map.
+    Left,
+    /// Stepped onto a cell it had already crossed in the same direction, so it will go round in
+    /// the same loop forever.
+    Looped,
+}
+
+struct GridExplorer<'a> {
+    data: &'a mut Grid<char>,
+    /*
+    Marks each explored position with the direction it was explored from, to help check for
+    cycles, or None if it hasn't been explored yet.
+    UPDATE: this used to use a special notation of chars, 'X' for not explored and 'N', 'S', 'E',
+    'W' for the direction, but Direction says the same thing without the translation
+    */
+    explored: Grid<Option<Direction>>,
+    next_move: Direction,
+    cur_pos: Point,
+    explored_count: u32,
+}
+
+impl<'a> GridExplorer<'a> {
+    //
+    // Getters
+    //
+    fn explored_count(&self) -> u32 {
+        self.explored_count
+    }
+
+    fn is_explored_pos(&self, pos: Point) -> bool {
+        self.explored[pos].is_some()
+    }
+
+    fn is_invalid_pos(&self, pos: Point) -> bool {
+        self.data[pos] == '#'
+    }
+
+    // UPDATE: this used to be checked by `is_next_pos_in_bounds` to avoid casting, but now the
+    // position is a signed Point which can step off the grid, and Grid does the bounds checking
+    fn next_pos(&self) -> Option<Point> {
+        let next = self.cur_pos + self.next_move.vector();
+        self.data.contains(next).then_some(next)
+    }
+
+    //
+    // Setters
+    //
+    // Returns whether there is a cycle
+    fn go_pos(&mut self, pos: Point) -> bool {
+        self.cur_pos = pos;
+        if !self.is_explored_pos(pos) {
+            self.set_explored(pos);
+            self.explored_count += 1;
+            false // no cycle
+        } else {
+            self.explored[pos] == Some(self.next_move)
+        }
+    }
+
+    fn set_explored(&mut self, pos: Point) {
+        // record the incoming direction
+        self.explored[pos] = Some(self.next_move);
+    }
+
+    fn switch_next_move(&mut self) {
+        self.next_move = self.next_move.turn_right();
+    }
+
+    fn step(&mut self) -> Step {
+        let Some(pos) = self.next_pos() else {
+            return Step::Left;
+        };
+        if self.is_invalid_pos(pos) {
+            self.switch_next_move();
+            Step::Turned
+        } else if self.go_pos(pos) {
+            Step::Looped
+        } else {
+            Step::Moved
+        }
+    }
+
+    // walks until the guard leaves the grid, or finds a cycle
+    fn finish(&mut self) -> Step {
+        loop {
+            match self.step() {
+                Step::Moved | Step::Turned => {}
+                end => return end,
+            }
+        }
+    }
+}
+
+// this started as an impl of the 'From' trait but while trying to optimise I realised that it
+// only accepts owned input.
+impl<'a> GridExplorer<'a> {
+    fn from(data: &'a mut Grid<char>) -> GridExplorer<'a> {
+        let start = data
+            .find(|&ch| ch == '^')
+            .expect("parse_input makes sure there is a guard");
+        let cur_pos = Point::from_index(start).expect("grid positions fit in isize");
+        let mut explored = Grid::new(data.rows(), data.cols(), None);
+        // mark the start as explored (facing north), otherwise it is counted a second time if
+        // the guard walks back through it
+        explored[cur_pos] = Some(Direction::North);
+
+        GridExplorer {
+            data,
+            explored,
+            next_move: Direction::North,
+            cur_pos,
+            explored_count: 1,
+        }
+    }
+}
+
+fn solve_part_one(grid: &Grid<char>) -> u32 {
+    let mut owned_grid = grid.clone();
+    let mut explorer = GridExplorer::from(&mut owned_grid);
+    // the puzzle promises the guard leaves, but a map where it doesn't would otherwise hang here
+    explorer.finish();
+    explorer.explored_count()
+}
+
+/// Every position where one more obstruction sends the guard round in a loop, which is what part
+/// two counts.
+// clearly this is extremely extremely inefficient... but it works...
+// TODO, optimize
+// UPDATE 01 - using the same grid in memory for every iteration of the search, saves time
+// reallocating the memory
+pub fn loop_obstructions(grid: &Grid<char>) -> Vec<(usize, usize)> {
+    let mut obstructions = Vec::new();
+    let mut mutated_grid = grid.clone();
+    for obstruction in grid.positions() {
+        if grid[obstruction] == '.' {
+            mutated_grid[obstruction] = '#'; // mutate
+            let mut explorer = GridExplorer::from(&mut mutated_grid);
+            if explorer.finish() == Step::Looped {
+                obstructions.push(obstruction);
+            }
+            mutated_grid[obstruction] = '.'; // restore grid to backtrack
+        }
+    }
+    obstructions
+}
+
+fn solve_part_two(grid: &Grid<char>) -> u32 {
+    loop_obstructions(grid).len() as u32
+}
+
+/// The cells the guard visits, coloured by the direction it first crossed them in, then the
+/// obstructions which would make it loop, and where it starts.
+pub fn layers(grid: &Grid<char>) -> Vec<Layer> {
+    let mut owned_grid = grid.clone();
+    let mut explorer = GridExplorer::from(&mut owned_grid);
+    let start = explorer
+        .cur_pos
+        .to_index()
+        .expect("the guard is on the map");
+    explorer.finish();
+
+    let mut visited = Layer::new(
+        "visited",
+        &[
+            Color::rgb(0x9e, 0xc5, 0xfe),
+            Color::rgb(0xa3, 0xe4, 0xa3),
+            Color::rgb(0xfe, 0xd7, 0x9e),
+            Color::rgb(0xe4, 0xb3, 0xf5),
+        ],
+    );
+    for (pos, direction) in explorer.explored.iter() {
+        if let Some(direction) = direction {
+            let shade = Direction::ALL
+                .iter()
+                .position(|d| d == direction)
+                .unwrap_or_default();
+            visited.mark(pos, shade);
+        }
+    }
+    let mut obstructions = Layer::new("obstructions", &[Color::rgb(0xd6, 0x27, 0x28)]);
+    for pos in loop_obstructions(grid) {
+        obstructions.mark(pos, 0);
+    }
+    let mut guard = Layer::new("guard", &[Color::rgb(0xff, 0xbf, 0x00)]);
+    guard.mark(start, 0);
+    vec![visited, obstructions, guard]
+}
+
+/// The state of the patrol after a step, for showing it.
+pub struct Frame<'a> {
+    /// The step just taken, or `None` for where the guard starts.
+    pub step: Option<Step>,
+    /// How many steps have been taken so far, counting turns.
+    pub steps: usize,
+    explorer: &'a GridExplorer<'a>,
+    obstruction: Option<(usize, usize)>,
+}
+
+impl Frame<'_> {
+    pub fn facing(&self) -> Direction {
+        self.explorer.next_move
+    }
+
+    /// Cells visited so far, as counted by part one.
+    pub fn visited(&self) -> u32 {
+        self.explorer.explored_count()
+    }
+
+    /// Draws the map: the guard as `^>v<` in the direction it's facing, every visited cell with
+    /// an arrow in the direction the guard first crossed it, as recorded by `explored`, and the
+    /// extra obstruction, if there is one, as `O`. With `view`, only that many rows and columns
+    /// around the guard are drawn, so that the real input fits in a terminal. With `color`, the
+    /// guard, trail and obstruction are picked out with ANSI colours.
+    pub fn render(&self, view: Option<usize>, color: bool) -> String {
+        let explorer = self.explorer;
+        let guard = explorer
+            .cur_pos
+            .to_index()
+            .expect("the guard is on the map");
+        let window = |guard: usize, len: usize| {
+            let size = view.unwrap_or(len).min(len);
+            let start = guard.saturating_sub(size / 2).min(len - size);
+            start..start + size
+        };
+        let paint = |ch: char, code: &str| {
+            if color {
+                format!("\x1b[{code}m{ch}\x1b[0m")
+            } else {
+                ch.to_string()
+            }
+        };
+
+        let mut out = String::new();
+        for row in window(guard.0, explorer.data.rows()) {
+            for col in window(guard.1, explorer.data.cols()) {
+                let pos = (row, col);
+                let cell = if pos == guard {
+                    let arrow = match explorer.next_move {
+                        Direction::North => '^',
+                        Direction::East => '>',
+                        Direction::South => 'v',
+                        Direction::West => '<',
+                    };
+                    paint(arrow, "1;33")
+                } else if Some(pos) == self.obstruction {
+                    paint('O', "1;31")
+                } else if explorer.data[pos] == '#' {
+                    String::from("#")
+                } else if let Some(direction) = explorer.explored[pos] {
+                    let arrow = match direction {
+                        Direction::North => '↑',
+                        Direction::East => '→',
+                        Direction::South => '↓',
+                        Direction::West => '←',
+                    };
+                    paint(arrow, "36")
+                } else {
+                    String::from(".")
+                };
+                out.push_str(&cell);
+            }
+            out.push('\n');
+        }
+        out.push_str(&format!(
+            "step {}, {} cells visited, facing {:?}",
+            self.steps,
+            self.visited(),
+            self.facing()
+        ));
+        out
+    }
+}
+
+/// Walks the guard around `grid`, with one more obstruction at `obstruction` if given, showing
+/// each step to `show` until the guard leaves the map or goes round in a loop, or `show` breaks.
+/// Returns the last step taken, or an error if the obstruction isn't on an empty cell.
+pub fn patrol(
+    grid: &Grid<char>,
+    obstruction: Option<(usize, usize)>,
+    mut show: impl FnMut(&Frame) -> ControlFlow<()>,
+) -> Result<Option<Step>, String> {
+    let mut owned_grid = grid.clone();
+    if let Some(pos) = obstruction {
+        match owned_grid.get_mut(pos) {
+            Some(cell @ '.') => *cell = '#',
+            _ => {
+                return Err(format!(
+                    "line {}, column {} isn't an empty cell on the map",
+                    pos.0 + 1,
+                    pos.1 + 1
+                ))
+            }
+        }
+    }
+    let mut explorer = GridExplorer::from(&mut owned_grid);
+    let mut frame_step = None;
+    for steps in 0.. {
+        let frame = Frame {
+            step: frame_step,
+            steps,
+            explorer: &explorer,
+            obstruction,
+        };
+        if show(&frame).is_break() || matches!(frame_step, Some(Step::Left | Step::Looped)) {
+            break;
+        }
+        frame_step = Some(explorer.step());
+    }
+    Ok(frame_step)
+}
+
+impl Solution for Day06 {
+    type Input = Grid<char>;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(grid: &Self::Input) -> u32 {
+        solve_part_one(grid)
+    }
+
+    fn part_two(grid: &Self::Input) -> u32 {
+        solve_part_two(grid)
+    }
+
+    fn validate(input: &str) -> Vec<Violation> {
+        validate_input(input)
+    }
+}
@@ -0,0 +1,132 @@
+//! # Commentary
+//!
+//! Instinctively, I reached for DFS. Luckily, it didn't seem to be over-engineered for a variable
+//! number of operators because surely enough, in part 2, they asked to add another operator.
+//!
+//! # Things I tried and learnt
+//! - Using slices is a memory-efficient way of tracking the remaining elements in the array.
+//! Really learning to appreciate the Slice API in Rust.
+//!
+//! - Using a `match` statement is an elegant way to handle the various operations. It also helps
+//! for a combination check on whether there is any previous result and how to proceed with the
+//! operation.
+//!
+//! - The terminal condition for DFS should be when there are no more nums to process, it seems
+//! simple but midway I struggled with something more tricky like the length of `results` Vec.
+//! I think using a results Vec simplified certain things but threw me off in this way.
+//! TODO, I wonder how others modelled this thing.
+
+use crate::error::{self, ParseError};
+use crate::Solution;
+
+pub struct Day07;
+
+fn parse_input(input: &str) -> Result<Vec<(i64, Vec<i64>)>, ParseError> {
+    // TODO, tried using .take() to oeprate on the line sections without collecting into Vec<&str>
+    // but this proved too unweidly, must be a better way
+    // maybe I can split on both ":" and " "
+    // UPDATE: .split_once() is the better way, and it says when there is no ":" to split on
+    error::numbered_lines(input)
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(line_no, line)| {
+            let Some((target, nums)) = line.split_once(':') else {
+                return Err(ParseError::new(line_no, "expected `target: numbers`"));
+            };
+            let target = error::parse_number(line_no, line, target.trim())?;
+            let nums = nums
+                .split_whitespace()
+                .map(|num| error::parse_number(line_no, line, num))
+                .collect::<Result<Vec<i64>, _>>()?;
+            if nums.is_empty() {
+                return Err(ParseError::new(
+                    line_no,
+                    "expected at least one number after `:`",
+                ));
+            }
+            Ok((target, nums))
+        })
+        .collect()
+}
+
+enum Operator {
+    Add,
+    Multiply,
+    Concatenate,
+}
+
+fn concatenate(x: i64, y: i64) -> i64 {
+    format!("{}{}", x, y).parse().unwrap() // assume the output won't overflow i64::MAX
+}
+
+fn dfs_find_target(
+    nums: &[i64],
+    operators: &[Operator],
+    results: &mut Vec<i64>,
+    target: &i64,
+) -> bool {
+    if nums.is_empty() {
+        results.last().unwrap() == target
+    } else {
+        for operator in operators {
+            let num = *nums
+                .first()
+                .expect("should have at least one num to operate on");
+
+            let last_res = results.last();
+            let next_res = match (operator, last_res) {
+                (_, None) => num,
+                (Operator::Add, Some(l)) => num + l,
+                (Operator::Multiply, Some(l)) => num * l,
+                (Operator::Concatenate, Some(l)) => concatenate(*l, num), // don't forget to put in reverse order
+            };
+
+            results.push(next_res);
+            if dfs_find_target(&nums[1..], operators, results, target) {
+                return true; // stop DFS right away
+            }
+            results.pop(); // backtrack
+        }
+        false
+    }
+}
+
+fn solve_part_one(tasks: &[(i64, Vec<i64>)]) -> i64 {
+    tasks
+        .iter()
+        .filter(|(target, nums)| {
+            let mut results: Vec<i64> = vec![];
+            let operators = [Operator::Add, Operator::Multiply];
+            dfs_find_target(&nums[..], &operators, &mut results, target)
+        })
+        .map(|(target, _)| target)
+        .sum()
+}
+
+fn solve_part_two(tasks: &[(i64, Vec<i64>)]) -> i64 {
+    tasks
+        .iter()
+        .filter(|(target, nums)| {
+            let mut results: Vec<i64> = vec![];
+            let operators = [Operator::Add, Operator::Multiply, Operator::Concatenate];
+            dfs_find_target(&nums[..], &operators, &mut results, target)
+        })
+        .map(|(target, _)| target)
+        .sum()
+}
+
+impl Solution for Day07 {
+    type Input = Vec<(i64, Vec<i64>)>;
+    type Answer = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(tasks: &Self::Input) -> i64 {
+        solve_part_one(tasks)
+    }
+
+    fn part_two(tasks: &Self::Input) -> i64 {
+        solve_part_two(tasks)
+    }
+}
@@ -0,0 +1,343 @@
+//! # Commentary
+//!
+//! I was trying to reuse, as much as possible, the grid from day 06. But it didn't work out, as I
+//! go into more detail below.
+//!
+//! # Things I tried and learnt
+//!
+//! - Data structure choice. Using a Hashmap<char<Vec, (row, col)>> to aggregate each group of
+//! antennas, where each entry maps the antenna type, e.g. 'A', to a Vec of antenna positions.
+//! Actually, all we need is a Vec<Vec<(row, col)>>, without the identifier for the antenna type.
+//! However, including the antenna type actually makes it easier to aggregate the data in one pass.
+//! I eventually called this HashMap 'antenna_groups'.
+//!
+//! - Who owns the 'antenna_groups' HashMap? Initially, my first thought was to have the Grid struct
+//! own it, but turns out it's a bad idea. Assuming that I want to encapsulate the data, I would use
+//! a getter to return a reference to 'antenna_groups'. But this would mean that my getter method
+//! would need to borrow the entire Grid struct to read 'antenna_groups'. In turn, this would deny
+//! me a mutable ref to update state in the Grid struct.
+//!
+//! The problem is that Rust doesn't look at the implementation of the methods where I update state,
+//! so it doesn't know that I don't intend to mutate 'antenna_groups' only a separate counter field.
+//! One way of solving this would be to in-line everything so that the compiler can figure out that
+//! the fields I read and write from are disjoint - but clearly a no-no. Another would be to remove
+//! encapsulation and directly allow the caller to read 'antenna_groups' as a field - but I don't
+//! like this either. Finally, I decided to have the caller own 'antenna_groups', and write a getter
+//! on Grid to return an owned instance of it. Maybe, the larger problem here is, should the Grid
+//! struct and 'antenna_groups' be part of the same data structure? Perhaps not.
+//!
+//! - Grid struct less reusable than I thought. One limitation was that my boundary checking fn,
+//! `is_next_pos_in_bounds`, made the assumption that we only move one sqaure at a time. This is
+//! a valid assumption in many grid exploration problems, but not here. In hindsight, it was a poor
+//! fit because we aren't exactly doing an exploration here. So I eventually removed the
+//! `is_next_pos_in_bounds` fn. I could have refactored it, if not for the next learning point,
+//! which is about issues with integer types.
+//!
+//! - Over-engineering my code to avoid casting between integer types.
+//! TLDR: I think for purposes of this challenge, it makes thing so much easier to use casting and
+//! accept that it is fine for the nature of this input. We can still be aware with the limits of
+//! our code.
+//!
+//! I struggled with this in previous days as well. Looking back, it was obviously a mistake to
+//! make things too complicated. My concern was this: Due to the nature of using Vecs to represent
+//! the Grid, we have to work with `usize` to represent the respective rows and columns. I think
+//! this is in fact the correct and safe abstraction. However, due to the need to represent 4 (or
+//! more) directions of movement in a grid, we need to use negative numbers (i.e. `isize`). There
+//! are cases where the math operations involving `usize` and `isize` could be unsafe, in particular:
+//!
+//! 1. When we silently cast `row` or `col`, which are of type `usize`, to type `isize`. If the    
+//! value is too big to fit into isize (e.g. because the value is close to usize::MAX), an overflow
+//! will silently happen, resulting in a negative number.  
+//! Solution: Perform a range check before casting, or use TryFrom.
+//! 2. When we add 2 `isize`s. If result exceeds an `isize`, it will overflow and either panic, or
+//! silently wrapping around (depending on which mode).
+//! Solution: Using checked arithmetic, e.g. `checked_add`, or other similar methods.
+//! 3. When we cast `isize` back to `usize`. If the value is negative, the bits will be
+//! reinterpreted into a (very large) `usize` which is incorrect (sometimes referred to as
+//! wraparound).
+//! Solution: Perform a check before casting, or use TryFrom.
+//!
+//! I tried to solve problem #1 in Day 6 by writing the `is_next_pos_in_bounds`, but this wasn't
+//! sufficiently extendable since it assumed that we move 1 square at a time. When solving Day 8,
+//! I realised that there is no way to avoid a conversion if we want to make a generally reusable
+//! fn. Finally, my conclusion is: we aren't writing a payment module, and the input sizes are
+//! bounded and not expected to go anywhere the danger zones, it's probably fine to just cast away.
+//! UPDATE: in the end, I did deal with all three problems, but only once, in the `geometry` module.
+//! Positions are signed Points, the arithmetic on them is checked, and the only way back to a grid
+//! index is a checked conversion.
+//!
+//! - Finding an elegant way of comparing all-to-all antennas while using nested for-loops: One way
+//! was to start the inner loop at index 1 greater than the outer loop, to ensure that we would not
+//! repeat the same combination. This had the downside of having to repeat some code twice, since
+//! each combination of `tower_a` and `tower_b` can appear in two separate permutations (i.e. the
+//! antinode for `tower_a` due to `tower_b` and vice versa). The second way to actually iterate
+//! through the entire array of antennas in the inner loop again, but add check for whether
+//! `tower_a` != `tower_b`. Although this is less efficient, it is certainly more elegant, since
+//! all permutations are taken care of automatically.
+//!
+//! - Using geometric vectors to model the antenna calculations. Here, I'm referring not to the Vec
+//! data type in Rust, but the concept of geometric (or Euclidean) vectors. What I did was to
+//! iterate through various examples to generalize a formula to calculate the antinodes, which was
+//! tedious to do on the fly. Instead, I learnt that you could use a Vector to model the
+//! displacement from antenna A to antenna B (Vector A->B (aka from A to B) = B - A). Then it would
+//! be a matter of applying the vector to the point B (or the negative to point A) to calculate the
+//! antinodes. An even easier method would be to double the Vector from the source antenna A, which
+//! would give the antinode further from A. In part 2, we could transform the vector by a linearly
+//! increasing scalar and apply it multiple times until we leave the grid to get the series of
+//! antinodes. I think this is a much cleaner way to think about it.
+
+use std::collections::HashMap;
+
+use crate::error::{ParseError, Violation};
+use crate::geometry::{Point, Vector};
+use crate::grid::Grid;
+use crate::render::{Color, Layer};
+use crate::Solution;
+
+pub struct Day08;
+
+fn parse_input(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(input)
+}
+
+// Parsing already makes sure the map is rectangular. Other than that, anything which isn't a `.`
+// would be taken for an antenna, so check they really are letters or digits
+fn validate_input(input: &str) -> Vec<Violation> {
+    let Ok(grid) = parse_input(input) else {
+        return Vec::new(); // parse has already said what's wrong
+    };
+    grid.iter()
+        .filter(|(_, &ch)| ch != '.' && !ch.is_ascii_alphanumeric())
+        .map(|((row, col), ch)| {
+            Violation::on_line(
+                row + 1,
+                format!(
+                    "unexpected `{ch}` at column {}, antennas should be letters or digits",
+                    col + 1
+                ),
+            )
+        })
+        .collect()
+}
+
+struct GridExplorer {
+    data: Grid<char>,
+    marked: Grid<bool>,
+    cur_pos: Point,
+    marked_count: u32,
+}
+
+impl From<Grid<char>> for GridExplorer {
+    fn from(data: Grid<char>) -> Self {
+        let marked = Grid::new(data.rows(), data.cols(), false);
+
+        GridExplorer {
+            data,
+            marked,
+            cur_pos: Point::new(0, 0),
+            marked_count: 0,
+        }
+    }
+}
+
+impl GridExplorer {
+    //
+    // Getters
+    //
+    fn antenna_groups(&self) -> HashMap<char, Vec<Point>> {
+        let mut antenna_groups: HashMap<char, Vec<Point>> = HashMap::new();
+        for (pos, &char) in self.data.iter() {
+            if char != '.' {
+                let vec = antenna_groups.entry(char).or_default();
+                vec.push(Point::from_index(pos).expect("grid positions fit in isize"));
+            }
+        }
+        antenna_groups
+    }
+
+    fn marked_count(&self) -> u32 {
+        self.marked_count
+    }
+
+    // UPDATE: there is a much simpler way using the concept of Vectors, i.e.,
+    // Vector A->B (aka from A to B) = B - A
+    // We basically wish to find the Vector from Dest to Source, which will then be added to
+    // source position to produce the antinode closer to source, so subtract Dest from Source:
+    // let (srcx, srcy) = (self.cur_pos.0 as isize, self.cur_pos.1 as isize);
+    // let (destx, desty) = (dest.0 as isize, dest.1 as isize);
+    // (srcx - destx, srcy - desty)
+    fn current_antinode_delta_due_to(&self, dest: &Point) -> Vector {
+        /*
+        Deriving logic from examples
+        source: (3, 6)
+        dest: (4, 9)
+        antinode for source =
+        -(4 - 3) because dest > source
+        -(9 - 6) because dest > source
+
+        source: (3, 6)
+        dest: (4, 5)
+        antinode for source =
+        -(4 - 3) because dest > source -(dest - source)
+        (6 - 5) because source > dest (soruce - dest)
+        */
+
+        // UPDATE 2: the body used to follow the derivation above one component at a time,
+        // comparing the usize positions so as never to subtract a bigger one from a smaller one:
+        // let dr: isize = if sourcer > destr {
+        //     (sourcer - destr) as isize
+        // } else {
+        //     -((destr - sourcer) as isize)
+        // };
+        // and the same for the columns. Now that positions are signed Points, it's just the
+        // Vector formula from the first UPDATE
+        self.cur_pos - *dest
+    }
+
+    //
+    // Setters
+    //
+    fn set_current(&mut self, cur: Point) {
+        self.cur_pos = cur;
+    }
+
+    fn mark_antinode_if_inbound(&mut self, delta: Vector) -> Option<Point> {
+        // UPDATE: no more casting here either, Points can step off the grid and Grid does the
+        // bounds checking
+        let antinode = self.cur_pos + delta;
+        if self.data.contains(antinode) {
+            self.set_marked(antinode);
+            Some(antinode)
+        } else {
+            None
+        }
+    }
+
+    fn set_marked(&mut self, pos: Point) {
+        if !self.marked[pos] {
+            self.marked[pos] = true;
+            self.marked_count += 1;
+        }
+    }
+}
+
+// UPDATE: both parts used to return the count straight away, but `aoc render` wants to see
+// where the antinodes are, which is what `marked` has been keeping track of all along
+fn find_antinodes(grid: &Grid<char>) -> GridExplorer {
+    let mut explorer = GridExplorer::from(grid.clone());
+
+    let antenna_groups = explorer.antenna_groups();
+    for group in antenna_groups.values() {
+        for (i, &tower_a) in group.iter().enumerate() {
+            for &tower_b in &group[i + 1..] {
+                explorer.set_current(tower_a);
+                let delta = explorer.current_antinode_delta_due_to(&tower_b);
+                explorer.mark_antinode_if_inbound(delta);
+                // flip around
+                explorer.set_current(tower_b);
+                let delta = explorer.current_antinode_delta_due_to(&tower_a);
+                explorer.mark_antinode_if_inbound(delta);
+            }
+        }
+    }
+    explorer
+}
+
+fn find_harmonics(grid: &Grid<char>) -> GridExplorer {
+    let mut explorer = GridExplorer::from(grid.clone());
+    let antenna_groups = explorer.antenna_groups();
+    for group in antenna_groups.values() {
+        for &tower_a in group {
+            for &tower_b in group {
+                if tower_a != tower_b {
+                    // if we have at least a pair, then tower a and tower b are themselves antinodes
+                    explorer.set_marked(tower_a);
+                    explorer.set_marked(tower_b);
+                    explorer.set_current(tower_a);
+                    let delta = explorer.current_antinode_delta_due_to(&tower_b);
+                    while let Some(antinode) = explorer.mark_antinode_if_inbound(delta) {
+                        explorer.set_current(antinode);
+                    }
+                }
+            }
+        }
+    }
+    explorer
+}
+
+fn solve_part_one(grid: &Grid<char>) -> u32 {
+    find_antinodes(grid).marked_count()
+}
+
+fn solve_part_two(grid: &Grid<char>) -> u32 {
+    find_harmonics(grid).marked_count()
+}
+
+/// Part two's antinodes, then part one's on top of them, then the antennas coloured by
+/// frequency.
+pub fn layers(grid: &Grid<char>) -> Vec<Layer> {
+    let marked_layer = |name, color, explorer: GridExplorer| {
+        let mut layer = Layer::new(name, &[color]);
+        for (pos, &marked) in explorer.marked.iter() {
+            if marked {
+                layer.mark(pos, 0);
+            }
+        }
+        layer
+    };
+    let harmonics = marked_layer(
+        "harmonics",
+        Color::rgb(0xc6, 0xdb, 0xef),
+        find_harmonics(grid),
+    );
+    let antinodes = marked_layer(
+        "antinodes",
+        Color::rgb(0x31, 0x82, 0xbd),
+        find_antinodes(grid),
+    );
+
+    let mut frequencies: Vec<char> = grid
+        .iter()
+        .map(|(_, &ch)| ch)
+        .filter(|&ch| ch != '.')
+        .collect();
+    frequencies.sort_unstable();
+    frequencies.dedup();
+    let mut antennas = Layer::new(
+        "antennas",
+        &[
+            Color::rgb(0xe6, 0x55, 0x0d),
+            Color::rgb(0x31, 0xa3, 0x54),
+            Color::rgb(0x75, 0x6b, 0xb1),
+            Color::rgb(0xd6, 0x61, 0x6b),
+            Color::rgb(0x8c, 0x6d, 0x31),
+        ],
+    );
+    for (pos, ch) in grid.iter() {
+        if let Ok(shade) = frequencies.binary_search(ch) {
+            antennas.mark(pos, shade);
+        }
+    }
+    vec![harmonics, antinodes, antennas]
+}
+
+impl Solution for Day08 {
+    type Input = Grid<char>;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(grid: &Self::Input) -> u32 {
+        solve_part_one(grid)
+    }
+
+    fn part_two(grid: &Self::Input) -> u32 {
+        solve_part_two(grid)
+    }
+
+    fn validate(input: &str) -> Vec<Violation> {
+        validate_input(input)
+    }
+}
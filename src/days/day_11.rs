@@ -0,0 +1,93 @@
+//! # Commentary
+//!
+//! Other than brute force, I was thinking of how to apply Dynamic Programming to this problem,
+//! given that there are repeated sub-problems and there exists a recursive relationship
+//! between the problems. However, I struggled think of an optimal 2-D DP grid, short of trying
+//! every single possible starting number from 0 to a very high number which would cover all the
+//! cases. In this case, top-down memoization seemed easier, even though I dislike it as it the
+//! memoization happens in a haphazard fashion rather than in a sequential fashion. Also, using a
+//! HashMap to memoize has a performance penalty as compared to using a Vec (which only works if
+//! you proceed in a sequential fashion). Anyhow, this was what I came up with.
+//!
+//! # Things I tried and learnt
+
+use std::collections::HashMap;
+
+use crate::error::{self, ParseError};
+use crate::Solution;
+
+pub struct Day11;
+
+fn parse_input(input: &str) -> Result<Vec<u64>, ParseError> {
+    let mut nums = Vec::new();
+    for (line_no, line) in error::numbered_lines(input) {
+        for s in line.split_whitespace() {
+            nums.push(error::parse_number::<u64>(line_no, line, s)?);
+        }
+    }
+    Ok(nums)
+}
+
+fn solve_part_one(nums: &[u64]) -> u64 {
+    let mut cache: HashMap<(u64, u64), u64> = HashMap::new();
+    nums.iter()
+        .map(|&num| count_pebbles_recursive(num, 25, &mut cache))
+        .sum()
+}
+
+fn solve_part_two(nums: &[u64]) -> u64 {
+    let mut cache: HashMap<(u64, u64), u64> = HashMap::new();
+    nums.iter()
+        .map(|&num| count_pebbles_recursive(num, 75, &mut cache))
+        .sum()
+}
+
+fn count_pebbles_recursive(num: u64, level: u64, cache: &mut HashMap<(u64, u64), u64>) -> u64 {
+    let num_str = num.to_string();
+
+    if level == 1 {
+        if num == 0 {
+            1
+        } else if num_str.len().is_multiple_of(2) {
+            2
+        } else {
+            1
+        }
+    } else {
+        // TODO mention this, technically using tuples as key is not efficient
+        if let Some(&result) = cache.get(&(num, level)) {
+            result
+        } else {
+            let result = if num == 0 {
+                count_pebbles_recursive(1, level - 1, cache)
+            } else if num_str.len().is_multiple_of(2) {
+                let mid = num_str.len() / 2;
+                let left_num = num_str[..mid].parse::<u64>().unwrap();
+                let right_num = num_str[mid..].parse::<u64>().unwrap();
+                count_pebbles_recursive(left_num, level - 1, cache)
+                    + count_pebbles_recursive(right_num, level - 1, cache)
+            } else {
+                count_pebbles_recursive(num * 2024, level - 1, cache)
+            };
+            cache.insert((num, level), result);
+            result
+        }
+    }
+}
+
+impl Solution for Day11 {
+    type Input = Vec<u64>;
+    type Answer = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(nums: &Self::Input) -> u64 {
+        solve_part_one(nums)
+    }
+
+    fn part_two(nums: &Self::Input) -> u64 {
+        solve_part_two(nums)
+    }
+}
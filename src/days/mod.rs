@@ -0,0 +1,21 @@
+pub mod day_01;
+pub mod day_02;
+pub mod day_03;
+pub mod day_04;
+pub mod day_05;
+pub mod day_06;
+pub mod day_07;
+pub mod day_08;
+pub mod day_09;
+pub mod day_11;
+
+pub use day_01::Day01;
+pub use day_02::Day02;
+pub use day_03::Day03;
+pub use day_04::Day04;
+pub use day_05::Day05;
+pub use day_06::Day06;
+pub use day_07::Day07;
+pub use day_08::Day08;
+pub use day_09::Day09;
+pub use day_11::Day11;
@@ -0,0 +1,130 @@
+//! Differential testing: solving generated inputs with both a day's own solution and each of its
+//! [`Alternative`]s, to find inputs they give different answers for. Any disagreement found is
+//! shrunk to the smallest input that still shows it, since the first one found is rarely the
+//! easiest to debug.
+
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::generate;
+use crate::pool;
+use crate::registry::Puzzle;
+use crate::Alternative;
+
+/// An input that a day's solution and one of its alternatives give different answers for. A
+/// panic counts as an answer, i.e. `panicked: <message>`, so that a crash on either side is
+/// reported as a disagreement too.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Disagreement {
+    pub day: u8,
+    pub alternative: &'static str,
+    pub input: String,
+    /// The answer of the day's own solution.
+    pub expected: String,
+    /// The answer of the alternative.
+    pub found: String,
+}
+
+impl fmt::Display for Disagreement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day_{:02} gives {} but {} gives {} for:\n{}",
+            self.day, self.expected, self.alternative, self.found, self.input
+        )
+    }
+}
+
+/// Runs `f`, turning a panic into its message.
+fn catch(f: impl FnOnce() -> Option<String>) -> Option<String> {
+    panic::catch_unwind(AssertUnwindSafe(f))
+        .unwrap_or_else(|payload| Some(format!("panicked: {}", pool::panic_message(&*payload))))
+}
+
+/// Solves `input` both ways, and returns the two answers if they differ. An input which doesn't
+/// parse or breaks the day's assumptions has no right answer, so it never counts.
+pub fn compare(puzzle: &Puzzle, alternative: &Alternative, input: &str) -> Option<Disagreement> {
+    if !puzzle.validate(input).is_empty() {
+        return None;
+    }
+    let expected = catch(|| {
+        let run = puzzle.run(input, &[alternative.part]).ok()?;
+        run.parts.into_iter().next().map(|part_run| part_run.answer)
+    })?;
+    let found = catch(|| (alternative.solve)(input).ok())?;
+    (expected != found).then(|| Disagreement {
+        day: puzzle.day,
+        alternative: alternative.name,
+        input: input.to_string(),
+        expected,
+        found,
+    })
+}
+
+/// Looks for a disagreement on generated inputs of every size up to `max_size`, smallest first,
+/// from each of the `seeds`, and shrinks the first one found.
+pub fn search(
+    puzzle: &Puzzle,
+    alternative: &Alternative,
+    seeds: u64,
+    max_size: usize,
+) -> Option<Disagreement> {
+    (1..=max_size)
+        .flat_map(|size| (0..seeds).map(move |seed| (size, seed)))
+        .find_map(|(size, seed)| {
+            let input = generate::generate(puzzle.day, seed, size)?;
+            compare(puzzle, alternative, &input)
+        })
+        .map(|disagreement| shrink(puzzle, alternative, disagreement))
+}
+
+/// Every input one step smaller than `input`: without one of its lines, then without one of the
+/// words on a line, then without one character.
+fn smaller(input: &str) -> Vec<String> {
+    let lines: Vec<&str> = input.lines().collect();
+    let with_line = |i: usize, line: &str| {
+        let mut lines = lines.clone();
+        lines[i] = line;
+        lines.join("\n") + "\n"
+    };
+
+    let mut candidates = Vec::new();
+    for i in 0..lines.len() {
+        let mut without = lines.clone();
+        without.remove(i);
+        candidates.push(without.join("\n") + "\n");
+    }
+    for (i, line) in lines.iter().enumerate() {
+        let words: Vec<&str> = line.split(' ').collect();
+        for j in 0..words.len() {
+            let mut without = words.clone();
+            without.remove(j);
+            candidates.push(with_line(i, &without.join(" ")));
+        }
+    }
+    for (i, line) in lines.iter().enumerate() {
+        for (j, ch) in line.char_indices() {
+            let without = format!("{}{}", &line[..j], &line[j + ch.len_utf8()..]);
+            candidates.push(with_line(i, &without));
+        }
+    }
+    // taking out the only line leaves a blank one, which is no smaller
+    candidates.retain(|candidate| candidate.len() < input.len());
+    candidates
+}
+
+/// Makes `disagreement` as small as it will go, by taking out lines, words or characters one at
+/// a time for as long as the two still disagree.
+pub fn shrink(
+    puzzle: &Puzzle,
+    alternative: &Alternative,
+    mut disagreement: Disagreement,
+) -> Disagreement {
+    while let Some(smaller) = smaller(&disagreement.input)
+        .iter()
+        .find_map(|input| compare(puzzle, alternative, input))
+    {
+        disagreement = smaller;
+    }
+    disagreement
+}
@@ -0,0 +1,121 @@
+//! The error returned when a puzzle input can't be parsed, which points at where in the input it
+//! went wrong, e.g. "day_05 line 1177: expected `a|b`". Also the [`Violation`]s of inputs which
+//! do parse, but not into what the solution expects.
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// The day whose input this is. Parsers don't know their own day, so this is filled in by
+    /// whoever called them, see [`ParseError::in_day`].
+    pub day: Option<u8>,
+    /// 1-based line of the input.
+    pub line: usize,
+    /// 1-based column within the line, if the problem can be pinned down to one.
+    pub column: Option<usize>,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, message: impl Into<String>) -> ParseError {
+        ParseError {
+            day: None,
+            line,
+            column: None,
+            message: message.into(),
+        }
+    }
+
+    pub fn at_column(mut self, column: usize) -> ParseError {
+        self.column = Some(column);
+        self
+    }
+
+    pub fn in_day(mut self, day: u8) -> ParseError {
+        self.day = Some(day);
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day_{day:02} ")?;
+        }
+        write!(f, "line {}", self.line)?;
+        if let Some(column) = self.column {
+            write!(f, ", column {column}")?;
+        }
+        write!(f, ": {}", self.message)
+    }
+}
+
+impl Error for ParseError {}
+
+/// An input which parses, but breaks one of the assumptions a solution makes about it, see
+/// `Solution::validate`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Violation {
+    /// The day whose input this is, filled in like [`ParseError::day`].
+    pub day: Option<u8>,
+    /// 1-based line the assumption is broken on, unless it's about the input as a whole.
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+impl Violation {
+    pub fn new(message: impl Into<String>) -> Violation {
+        Violation {
+            day: None,
+            line: None,
+            message: message.into(),
+        }
+    }
+
+    pub fn on_line(line: usize, message: impl Into<String>) -> Violation {
+        Violation {
+            line: Some(line),
+            ..Violation::new(message)
+        }
+    }
+
+    pub fn in_day(mut self, day: u8) -> Violation {
+        self.day = Some(day);
+        self
+    }
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.day, self.line) {
+            (Some(day), Some(line)) => write!(f, "day_{day:02} line {line}: ")?,
+            (Some(day), None) => write!(f, "day_{day:02}: ")?,
+            (None, Some(line)) => write!(f, "line {line}: ")?,
+            (None, None) => {}
+        }
+        f.write_str(&self.message)
+    }
+}
+
+/// The lines of `input`, numbered from 1 to match [`ParseError::line`]. Like `str::lines`, this
+/// strips `\r\n` as well as `\n`.
+pub fn numbered_lines(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    input.lines().enumerate().map(|(i, line)| (i + 1, line))
+}
+
+/// The 1-based column where `token` starts in `line`. `token` must be a slice of `line`, as
+/// handed out by `split` and friends.
+pub fn column_of(line: &str, token: &str) -> usize {
+    let offset = token.as_ptr() as usize - line.as_ptr() as usize;
+    line[..offset].chars().count() + 1
+}
+
+/// Parses `token`, a slice of line `line_no`, as a number, or points at it if it isn't one.
+pub fn parse_number<T: FromStr>(line_no: usize, line: &str, token: &str) -> Result<T, ParseError> {
+    token.parse().map_err(|_| {
+        ParseError::new(line_no, format!("expected a number, found `{token}`"))
+            .at_column(column_of(line, token))
+    })
+}
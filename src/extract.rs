@@ -0,0 +1,149 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use regex::Regex;
+
+use crate::answers::Answers;
+use crate::input;
+use crate::runner::Part;
+
+/// What a saved puzzle page has to offer, one entry per part the page describes (i.e. just part
+/// one until it's solved).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PuzzlePage {
+    pub parts: Vec<PartText>,
+}
+
+/// The description of one part.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PartText {
+    /// Every `<pre><code>` block, i.e. the example inputs, but also any other illustrations.
+    pub code_blocks: Vec<String>,
+    /// The last highlighted `<code><em>` in the description, which is where the answer to the
+    /// example is given.
+    pub answer: Option<String>,
+}
+
+impl PuzzlePage {
+    /// Picks the page apart. Each part is its own `<article>`, so anything outside of them, like
+    /// the answer box, is ignored.
+    pub fn parse(html: &str) -> PuzzlePage {
+        let article = Regex::new(r"(?s)<article[^>]*>(.*?)</article>").unwrap();
+        let block = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap();
+        let highlight = Regex::new(r"(?s)<code><em>(.*?)</em></code>").unwrap();
+
+        let parts = article
+            .captures_iter(html)
+            .map(|caps| {
+                let text = &caps[1];
+                PartText {
+                    code_blocks: block
+                        .captures_iter(text)
+                        .map(|caps| decode_html(&caps[1]))
+                        .collect(),
+                    answer: highlight
+                        .captures_iter(text)
+                        .last()
+                        .map(|caps| decode_html(&caps[1]).trim().to_string()),
+                }
+            })
+            .collect();
+        PuzzlePage { parts }
+    }
+
+    /// Every code block on the page, in order, which is what `--block` counts.
+    pub fn code_blocks(&self) -> impl Iterator<Item = &str> {
+        self.parts
+            .iter()
+            .flat_map(|part| part.code_blocks.iter().map(String::as_str))
+    }
+
+    /// The example to save: the `block`th code block on the page as the input, along with the
+    /// answers to whichever parts have one. Most days only give one example for both parts,
+    /// so the answers are all taken to be about the same example.
+    pub fn example(&self, block: usize) -> Option<Example> {
+        let input = self.code_blocks().nth(block)?.to_string();
+        let answers = Part::BOTH
+            .into_iter()
+            .zip(&self.parts)
+            .filter_map(|(part, text)| Some((part, text.answer.clone()?)))
+            .collect();
+        Some(Example { input, answers })
+    }
+}
+
+/// Strips any tags, e.g. the `<em>`s used to highlight parts of an example, and decodes the
+/// entities the site uses.
+fn decode_html(html: &str) -> String {
+    let tags = Regex::new(r"<[^>]*>").unwrap();
+    tags.replace_all(html, "")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// An example input and the answers to it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Example {
+    pub input: String,
+    pub answers: Vec<(Part, String)>,
+}
+
+/// What [`save`] did.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Saved {
+    pub input_path: PathBuf,
+    /// The parts whose answers were recorded.
+    pub recorded: Vec<Part>,
+    /// The parts that already had a different example answer on record, which is left alone.
+    pub kept: Vec<Part>,
+}
+
+/// Saves `example` as the example input of `day` in `inputs_dir`, and its answers as `example`
+/// answers in the answers file. An existing example input is only replaced if `overwrite` is set,
+/// since the answers on record belong to it, and then they're replaced along with it. The empty
+/// file left by `aoc new` doesn't count.
+pub fn save(inputs_dir: &Path, day: u8, example: &Example, overwrite: bool) -> io::Result<Saved> {
+    let input_path = input::input_path(inputs_dir, day, true);
+    let existing = fs::read_to_string(&input_path).unwrap_or_default();
+    let differs = existing.trim_end() != example.input.trim_end();
+    let replacing = !existing.trim().is_empty() && differs;
+    if replacing && !overwrite {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!(
+                "{} already has a different example in it",
+                input_path.display()
+            ),
+        ));
+    }
+    fs::create_dir_all(inputs_dir)?;
+    fs::write(&input_path, &example.input)?;
+
+    let mut answers = Answers::load(inputs_dir)?;
+    let (mut recorded, mut kept) = (Vec::new(), Vec::new());
+    if replacing {
+        answers.replace(day, "example", &example.answers);
+        recorded.extend(example.answers.iter().map(|(part, _)| *part));
+    } else {
+        for (part, answer) in &example.answers {
+            if answers.get(day, *part, "example") == Some(answer) {
+                continue;
+            }
+            if answers.record(day, *part, "example", answer) {
+                recorded.push(*part);
+            } else {
+                kept.push(*part);
+            }
+        }
+    }
+    answers.save()?;
+    Ok(Saved {
+        input_path,
+        recorded,
+        kept,
+    })
+}
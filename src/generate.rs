@@ -0,0 +1,240 @@
+//! Seeded generators of valid puzzle inputs, for testing the solutions on more than the one real
+//! input and one example each day comes with, and for seeing how they scale.
+//!
+//! Each generator takes a `size`, which scales whatever makes that day's input bigger, e.g. the
+//! number of lines, or the width of the map. Generated inputs are always valid, i.e. they parse
+//! and don't break any of the assumptions checked by `Solution::validate`.
+
+use crate::rng::Rng;
+
+/// A generator for a day's input, from a seeded [`Rng`] and a size.
+pub type Generator = fn(&mut Rng, usize) -> String;
+
+/// The generator for `day`, if there is one. See the individual generators for what `size` means.
+pub fn generator(day: u8) -> Option<Generator> {
+    let generator: Generator = match day {
+        1 => location_lists,
+        2 => level_reports,
+        3 => corrupted_memory,
+        4 => word_search,
+        5 => rules_and_updates,
+        6 => guard_map,
+        7 => calibration_equations,
+        8 => antenna_map,
+        9 => disk_map,
+        11 => stones,
+        _ => return None,
+    };
+    Some(generator)
+}
+
+/// Generates an input for `day` of the given `size` from `seed`, the same one every time.
+pub fn generate(day: u8, seed: u64, size: usize) -> Option<String> {
+    let generator = generator(day)?;
+    Some(generator(&mut Rng::new(seed), size))
+}
+
+/// Day 01: `size` pairs of five digit location IDs. Some of the right hand IDs are copied from
+/// the left, since part two is only interesting if they show up in both lists.
+pub fn location_lists(rng: &mut Rng, size: usize) -> String {
+    let left: Vec<u64> = (0..size).map(|_| rng.range(10000..=99999)).collect();
+    let mut out = String::new();
+    for &l in &left {
+        let r = if rng.chance(0.3) {
+            *rng.choose(&left)
+        } else {
+            rng.range(10000..=99999)
+        };
+        out.push_str(&format!("{l}   {r}\n"));
+    }
+    out
+}
+
+/// Day 02: `size` reports of 5 to 8 levels. Most start out safe, and then some of them get one
+/// or two bad levels, so that there is something for the problem dampener to do.
+pub fn level_reports(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..size {
+        let len = rng.range(5..=8) as usize;
+        let increasing = rng.chance(0.5);
+        let mut level = rng.range(30..=60) as i64;
+        let mut levels = Vec::with_capacity(len);
+        for _ in 0..len {
+            levels.push(level);
+            let step = rng.range(1..=3) as i64;
+            level += if increasing { step } else { -step };
+        }
+        for _ in 0..rng.range(0..=2) {
+            let i = rng.index(len);
+            let jump = rng.range(1..=10) as i64 * if rng.chance(0.5) { 1 } else { -1 };
+            // levels are never negative, so don't jump below the bottom
+            levels[i] = (levels[i] + jump).max(1);
+        }
+        let levels: Vec<String> = levels.iter().map(i64::to_string).collect();
+        out.push_str(&levels.join(" "));
+        out.push('\n');
+    }
+    out
+}
+
+/// Day 03: roughly `size` characters of corrupted memory, i.e. junk with some `mul(a,b)`s, both
+/// real and broken, and `do()`s and `don't()`s mixed in.
+pub fn corrupted_memory(rng: &mut Rng, size: usize) -> String {
+    const JUNK: &[u8] = b"abdmnotul(),[]{}<>!@#$%^&*-+=_?:;' 0123456789";
+    let mut out = String::with_capacity(size + 16);
+    let mut line_len = 0;
+    while out.len() < size {
+        let piece = match rng.below(20) {
+            0..=2 => format!("mul({},{})", rng.range(1..=999), rng.range(1..=999)),
+            3 => format!("mul({}, {})", rng.range(1..=999), rng.range(1..=999)),
+            4 => format!("mul[{},{}]", rng.range(1..=999), rng.range(1..=999)),
+            5 => String::from("do()"),
+            6 => String::from("don't()"),
+            _ => char::from(*rng.choose(JUNK)).to_string(),
+        };
+        line_len += piece.len();
+        out.push_str(&piece);
+        if line_len > 3000 {
+            out.push('\n');
+            line_len = 0;
+        }
+    }
+    out.push('\n');
+    out
+}
+
+/// Day 04: a `size` by `size` word search, mostly of the letters in XMAS.
+pub fn word_search(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..size {
+        for _ in 0..size {
+            out.push(*rng.choose(&['X', 'M', 'A', 'S', 'X', 'M', 'A', 'S', 'Q']));
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// Day 05: `size` updates of 5 to 23 pages (always an odd number, so there is a middle page),
+/// and a rule for every pair of pages. About half of the updates are already in order.
+pub fn rules_and_updates(rng: &mut Rng, size: usize) -> String {
+    // pages are two digits like in the real input, and putting them in a random order up front
+    // means the rules can never contradict each other or go round in a circle
+    let mut pages: Vec<u64> = (10..=99).collect();
+    rng.shuffle(&mut pages);
+    pages.truncate(49);
+
+    let mut rules: Vec<(u64, u64)> = pages
+        .iter()
+        .enumerate()
+        .flat_map(|(i, &before)| pages[i + 1..].iter().map(move |&after| (before, after)))
+        .collect();
+    // but the rules are given in any old order
+    rng.shuffle(&mut rules);
+    let mut out = String::new();
+    for (before, after) in rules {
+        out.push_str(&format!("{before}|{after}\n"));
+    }
+    out.push('\n');
+
+    for _ in 0..size {
+        let len = 2 * rng.range(2..=11) as usize + 1;
+        let mut positions: Vec<usize> = (0..pages.len()).collect();
+        rng.shuffle(&mut positions);
+        positions.truncate(len);
+        if rng.chance(0.5) {
+            positions.sort_unstable();
+        }
+        let update: Vec<String> = positions.iter().map(|&i| pages[i].to_string()).collect();
+        out.push_str(&update.join(","));
+        out.push('\n');
+    }
+    out
+}
+
+/// Day 06: a `size` by `size` map with a few obstructions, and the guard facing north somewhere
+/// on it.
+pub fn guard_map(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let mut cells: Vec<char> = (0..size * size)
+        .map(|_| if rng.chance(0.03) { '#' } else { '.' })
+        .collect();
+    let guard = rng.index(cells.len());
+    cells[guard] = '^';
+    cells
+        .chunks(size)
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect()
+}
+
+/// Day 07: `size` equations of 2 to 8 numbers. The test value is worked out from a random choice
+/// of operators, so most equations can be made true, and then some of them are knocked off by
+/// one so that they can't.
+pub fn calibration_equations(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..size {
+        let len = rng.range(2..=8) as usize;
+        // two digit numbers keep even concatenating all of them well inside an i64
+        let nums: Vec<i64> = (0..len).map(|_| rng.range(1..=99) as i64).collect();
+        let mut target = nums[0];
+        for &num in &nums[1..] {
+            target = match rng.below(3) {
+                0 => target + num,
+                1 => target * num,
+                _ => format!("{target}{num}").parse().unwrap(),
+            };
+        }
+        if rng.chance(0.3) {
+            target += 1;
+        }
+        let nums: Vec<String> = nums.iter().map(i64::to_string).collect();
+        out.push_str(&format!("{target}: {}\n", nums.join(" ")));
+    }
+    out
+}
+
+/// Day 08: a `size` by `size` map with about one antenna in every 40 cells, on a handful of
+/// frequencies.
+pub fn antenna_map(rng: &mut Rng, size: usize) -> String {
+    let frequencies: Vec<char> = ('0'..='9').chain('a'..='z').chain('A'..='Z').collect();
+    let frequencies = &frequencies[..rng.range(1..=12) as usize];
+    let mut out = String::new();
+    for _ in 0..size {
+        for _ in 0..size {
+            let cell = if rng.chance(0.025) {
+                *rng.choose(frequencies)
+            } else {
+                '.'
+            };
+            out.push(cell);
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// Day 09: a disk map of `size` digits, rounded up to an odd number so that it starts and ends
+/// with a file. Files are 1 to 9 blocks long, free space 0 to 9.
+pub fn disk_map(rng: &mut Rng, size: usize) -> String {
+    let len = size.max(1) | 1;
+    let mut out: String = (0..len)
+        .map(|i| {
+            let digit = if i % 2 == 0 {
+                rng.range(1..=9)
+            } else {
+                rng.range(0..=9)
+            };
+            char::from(b'0' + digit as u8)
+        })
+        .collect();
+    out.push('\n');
+    out
+}
+
+/// Day 11: `size` stones with numbers of up to six digits.
+pub fn stones(rng: &mut Rng, size: usize) -> String {
+    let stones: Vec<String> = (0..size)
+        .map(|_| rng.range(0..=999_999).to_string())
+        .collect();
+    stones.join(" ") + "\n"
+}
@@ -0,0 +1,219 @@
+//! Signed points and vectors for moving around grids.
+//!
+//! Grids are indexed by `usize`, but moving around them needs negative numbers, and day 08's
+//! commentary goes into the ways that mixing the two can go wrong: casting a large `usize` to
+//! `isize`, overflowing when adding `isize`s, and casting a negative `isize` back to `usize`.
+//! Here, all of the arithmetic is done on `isize`, panics instead of silently wrapping around, and
+//! the only way back to a grid index is the checked [`Point::to_index`].
+
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A position on (or off) a grid, as a signed row and column.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub row: isize,
+    pub col: isize,
+}
+
+/// The displacement between two points, i.e. Vector A->B = B - A.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Vector {
+    pub d_row: isize,
+    pub d_col: isize,
+}
+
+impl Point {
+    pub const fn new(row: isize, col: isize) -> Point {
+        Point { row, col }
+    }
+
+    /// Converts a grid index into a point, or `None` if it doesn't fit into an `isize`. Indices
+    /// into an actual grid always fit, as a `Vec` can't hold more than `isize::MAX` bytes.
+    pub fn from_index((row, col): (usize, usize)) -> Option<Point> {
+        Some(Point {
+            row: isize::try_from(row).ok()?,
+            col: isize::try_from(col).ok()?,
+        })
+    }
+
+    /// Converts the point into a grid index, or `None` if it is above or left of the grid. Note
+    /// that it may still be below or right of the grid, see `Grid::get_point`.
+    pub fn to_index(self) -> Option<(usize, usize)> {
+        Some((
+            usize::try_from(self.row).ok()?,
+            usize::try_from(self.col).ok()?,
+        ))
+    }
+
+    pub fn checked_add(self, vector: Vector) -> Option<Point> {
+        Some(Point {
+            row: self.row.checked_add(vector.d_row)?,
+            col: self.col.checked_add(vector.d_col)?,
+        })
+    }
+}
+
+impl Vector {
+    pub const fn new(d_row: isize, d_col: isize) -> Vector {
+        Vector { d_row, d_col }
+    }
+
+    pub fn checked_mul(self, scalar: isize) -> Option<Vector> {
+        Some(Vector {
+            d_row: self.d_row.checked_mul(scalar)?,
+            d_col: self.d_col.checked_mul(scalar)?,
+        })
+    }
+}
+
+impl Add<Vector> for Point {
+    type Output = Point;
+
+    fn add(self, vector: Vector) -> Point {
+        self.checked_add(vector)
+            .expect("moving the point overflowed isize")
+    }
+}
+
+impl AddAssign<Vector> for Point {
+    fn add_assign(&mut self, vector: Vector) {
+        *self = *self + vector;
+    }
+}
+
+impl Sub<Vector> for Point {
+    type Output = Point;
+
+    fn sub(self, vector: Vector) -> Point {
+        self + -vector
+    }
+}
+
+impl SubAssign<Vector> for Point {
+    fn sub_assign(&mut self, vector: Vector) {
+        *self = *self - vector;
+    }
+}
+
+/// The vector from `other` to `self`.
+impl Sub<Point> for Point {
+    type Output = Vector;
+
+    fn sub(self, other: Point) -> Vector {
+        Vector {
+            d_row: self
+                .row
+                .checked_sub(other.row)
+                .expect("vector between points overflowed isize"),
+            d_col: self
+                .col
+                .checked_sub(other.col)
+                .expect("vector between points overflowed isize"),
+        }
+    }
+}
+
+impl Add for Vector {
+    type Output = Vector;
+
+    fn add(self, other: Vector) -> Vector {
+        Vector {
+            d_row: self
+                .d_row
+                .checked_add(other.d_row)
+                .expect("adding vectors overflowed isize"),
+            d_col: self
+                .d_col
+                .checked_add(other.d_col)
+                .expect("adding vectors overflowed isize"),
+        }
+    }
+}
+
+impl Sub for Vector {
+    type Output = Vector;
+
+    fn sub(self, other: Vector) -> Vector {
+        self + -other
+    }
+}
+
+impl Neg for Vector {
+    type Output = Vector;
+
+    fn neg(self) -> Vector {
+        self * -1
+    }
+}
+
+impl Mul<isize> for Vector {
+    type Output = Vector;
+
+    fn mul(self, scalar: isize) -> Vector {
+        self.checked_mul(scalar)
+            .expect("scaling the vector overflowed isize")
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.row, self.col)
+    }
+}
+
+/// The four orthogonal directions, where North is towards row 0.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// Clockwise from North.
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// The unit vector one step in this direction.
+    pub fn vector(self) -> Vector {
+        match self {
+            Direction::North => Vector::new(-1, 0),
+            Direction::East => Vector::new(0, 1),
+            Direction::South => Vector::new(1, 0),
+            Direction::West => Vector::new(0, -1),
+        }
+    }
+
+    pub fn turn_right(self) -> Direction {
+        match self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+        }
+    }
+
+    pub fn turn_left(self) -> Direction {
+        self.opposite().turn_right()
+    }
+
+    pub fn opposite(self) -> Direction {
+        self.turn_right().turn_right()
+    }
+
+    /// The first letter of the direction, e.g. `'N'`.
+    pub fn to_char(self) -> char {
+        match self {
+            Direction::North => 'N',
+            Direction::East => 'E',
+            Direction::South => 'S',
+            Direction::West => 'W',
+        }
+    }
+}
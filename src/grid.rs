@@ -0,0 +1,255 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::error::ParseError;
+use crate::geometry::Point;
+
+/// Offsets to the 4 orthogonal neighbours, in the order N, E, S, W.
+pub const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// Offsets to all 8 neighbours, clockwise from N.
+pub const ALL_DIRECTIONS: [(isize, isize); 8] = [
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+];
+
+/// A rectangular grid, stored row by row in a single flat `Vec` rather than a `Vec<Vec<T>>`, so
+/// that the whole grid is one allocation and every row is guaranteed to be the same width.
+///
+/// Positions are `(row, col)` pairs of `usize`, as they were in the `Vec<Vec<char>>` grids. Moving
+/// around the grid is done with signed `(d_row, d_col)` offsets through [`Grid::offset`], which
+/// does the bounds checking in one place, so that it doesn't have to be repeated in every day.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    rows: usize,
+    cols: usize,
+}
+
+impl Grid<char> {
+    /// Parses a grid with one char per cell. Trailing whitespace on each line (including the `\r`
+    /// of CRLF line endings) and trailing blank lines are ignored.
+    ///
+    /// Fails if the lines aren't all the same width.
+    pub fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+        Grid::parse_with(input, |ch| ch)
+    }
+}
+
+impl<T> Grid<T> {
+    /// Like [`Grid::parse`], but converts every char into a cell with `f`.
+    pub fn parse_with(input: &str, mut f: impl FnMut(char) -> T) -> Result<Grid<T>, ParseError> {
+        let lines: Vec<&str> = input.trim_end().lines().map(str::trim_end).collect();
+        let cols = lines.first().map_or(0, |line| line.chars().count());
+        let mut cells = Vec::with_capacity(lines.len() * cols);
+        for (row, line) in lines.iter().enumerate() {
+            let width = line.chars().count();
+            if width != cols {
+                return Err(ParseError::new(
+                    row + 1,
+                    format!("expected {cols} columns like the first line, found {width}"),
+                ));
+            }
+            cells.extend(line.chars().map(&mut f));
+        }
+        Ok(Grid {
+            cells,
+            rows: lines.len(),
+            cols,
+        })
+    }
+
+    /// Builds a grid from its cells in row order. Panics if there aren't exactly `rows * cols`.
+    pub fn from_cells(rows: usize, cols: usize, cells: Vec<T>) -> Grid<T> {
+        assert_eq!(
+            cells.len(),
+            rows * cols,
+            "a {rows}x{cols} grid needs {} cells",
+            rows * cols
+        );
+        Grid { cells, rows, cols }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn in_bounds(&self, (row, col): (usize, usize)) -> bool {
+        row < self.rows && col < self.cols
+    }
+
+    pub fn get(&self, pos: (usize, usize)) -> Option<&T> {
+        if self.in_bounds(pos) {
+            Some(&self.cells[pos.0 * self.cols + pos.1])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: (usize, usize)) -> Option<&mut T> {
+        if self.in_bounds(pos) {
+            Some(&mut self.cells[pos.0 * self.cols + pos.1])
+        } else {
+            None
+        }
+    }
+
+    /// Whether `point` is on the grid, which it may not be in any direction.
+    pub fn contains(&self, point: Point) -> bool {
+        point.to_index().is_some_and(|pos| self.in_bounds(pos))
+    }
+
+    pub fn get_point(&self, point: Point) -> Option<&T> {
+        self.get(point.to_index()?)
+    }
+
+    pub fn get_point_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.get_mut(point.to_index()?)
+    }
+
+    /// The position `(d_row, d_col)` away from `pos`, if it is still on the grid. This is the one
+    /// place where signed offsets meet unsigned positions, so the arithmetic is all checked.
+    pub fn offset(
+        &self,
+        (row, col): (usize, usize),
+        (dr, dc): (isize, isize),
+    ) -> Option<(usize, usize)> {
+        let pos = (row.checked_add_signed(dr)?, col.checked_add_signed(dc)?);
+        self.in_bounds(pos).then_some(pos)
+    }
+
+    /// The orthogonal neighbours of `pos` which are on the grid.
+    pub fn neighbors4(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        ORTHOGONAL
+            .iter()
+            .filter_map(move |&delta| self.offset(pos, delta))
+    }
+
+    /// The orthogonal and diagonal neighbours of `pos` which are on the grid.
+    pub fn neighbors8(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        ALL_DIRECTIONS
+            .iter()
+            .filter_map(move |&delta| self.offset(pos, delta))
+    }
+
+    /// Every position on the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let cols = self.cols;
+        (0..self.rows).flat_map(move |row| (0..cols).map(move |col| (row, col)))
+    }
+
+    /// Every cell along with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The position of the first cell, row by row, which matches `predicate`.
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<(usize, usize)> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.cols..(row + 1) * self.cols]
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.cols, "column {col} is out of bounds");
+        self.cells.iter().skip(col).step_by(self.cols)
+    }
+
+    pub fn iter_rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks() panics on a chunk size of 0, which an empty grid would otherwise give
+        self.cells.chunks(self.cols.max(1))
+    }
+
+    pub fn iter_columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.cols).map(move |col| self.column(col))
+    }
+
+    /// A grid of the same size, with every cell converted by `f`.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// A `rows` by `cols` grid with every cell set to `value`.
+    pub fn new(rows: usize, cols: usize, value: T) -> Grid<T> {
+        Grid {
+            cells: vec![value; rows * cols],
+            rows,
+            cols,
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: (usize, usize)) -> &T {
+        self.get(pos).unwrap_or_else(|| {
+            panic!(
+                "position {pos:?} is out of bounds for a {}x{} grid",
+                self.rows, self.cols
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, pos: (usize, usize)) -> &mut T {
+        let (rows, cols) = (self.rows, self.cols);
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("position {pos:?} is out of bounds for a {rows}x{cols} grid"))
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get_point(point).unwrap_or_else(|| {
+            panic!(
+                "point {point} is out of bounds for a {}x{} grid",
+                self.rows, self.cols
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        let (rows, cols) = (self.rows, self.cols);
+        self.get_point_mut(point)
+            .unwrap_or_else(|| panic!("point {point} is out of bounds for a {rows}x{cols} grid"))
+    }
+}
+
+/// Prints the grid the way it is written in the puzzle input, one row per line.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.iter_rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,127 @@
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use clap::Parser;
+
+use crate::generate;
+
+/// Environment variable that overrides where the puzzle inputs are read from.
+pub const INPUTS_DIR_ENV: &str = "AOC_INPUTS_DIR";
+
+/// Where the puzzle inputs live unless told otherwise, i.e. `src/input` in this repo. Resolved
+/// against the manifest rather than the CWD, so the binaries work from any directory.
+pub fn default_inputs_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("src")
+        .join("input")
+}
+
+/// Path of a day's real input, e.g. `day_06.txt`, or its example input, e.g. `day_06_test.txt`.
+pub fn input_path(inputs_dir: &Path, day: u8, example: bool) -> PathBuf {
+    let suffix = if example { "_test" } else { "" };
+    inputs_dir.join(format!("day_{day:02}{suffix}.txt"))
+}
+
+/// Which input to solve against.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    /// The day's real input from the inputs directory.
+    Real,
+    /// The day's example input from the inputs directory.
+    Example,
+    /// An explicit file.
+    File(PathBuf),
+    /// Whatever is piped into stdin.
+    Stdin,
+    /// A random input made up by the day's generator, see [`crate::generate`].
+    Generated { size: usize, seed: u64 },
+}
+
+impl InputSource {
+    /// Short label for the input, e.g. in error messages or reports.
+    pub fn name(&self) -> String {
+        match self {
+            InputSource::Real => String::from("real"),
+            InputSource::Example => String::from("example"),
+            InputSource::File(path) => path.display().to_string(),
+            InputSource::Stdin => String::from("stdin"),
+            InputSource::Generated { size, seed } => format!("generated-{size}-{seed}"),
+        }
+    }
+}
+
+/// Command line flags for choosing the input, shared by the `aoc` runner and the day binaries.
+#[derive(Parser, Clone, Debug, Default)]
+pub struct InputArgs {
+    /// Use the day's example input, i.e. `day_XX_test.txt`
+    #[arg(long, conflicts_with = "input")]
+    pub example: bool,
+    /// Read the input from this file instead, or from stdin if `-`
+    #[arg(long, value_name = "PATH")]
+    pub input: Option<PathBuf>,
+    /// Solve a random input of this size instead, made up by the day's generator
+    #[arg(long, value_name = "SIZE", conflicts_with_all = ["example", "input"])]
+    pub generate: Option<usize>,
+    /// Seed for `--generate`, so the same input can be made again [default: 0]
+    #[arg(long, value_name = "SEED", requires = "generate")]
+    pub seed: Option<u64>,
+    /// Directory containing the `day_XX.txt` inputs [env: AOC_INPUTS_DIR] [default: src/input]
+    #[arg(long, value_name = "DIR")]
+    pub inputs_dir: Option<PathBuf>,
+}
+
+impl InputArgs {
+    pub fn source(&self) -> InputSource {
+        if let Some(size) = self.generate {
+            return InputSource::Generated {
+                size,
+                seed: self.seed.unwrap_or_default(),
+            };
+        }
+        match &self.input {
+            Some(path) if path.as_os_str() == "-" => InputSource::Stdin,
+            Some(path) => InputSource::File(path.clone()),
+            None if self.example => InputSource::Example,
+            None => InputSource::Real,
+        }
+    }
+
+    /// The `--inputs-dir` flag if given, then the environment variable, then the default.
+    pub fn inputs_dir(&self) -> PathBuf {
+        self.inputs_dir
+            .clone()
+            .or_else(|| env::var_os(INPUTS_DIR_ENV).map(PathBuf::from))
+            .unwrap_or_else(default_inputs_dir)
+    }
+
+    pub fn load(&self, day: u8) -> io::Result<String> {
+        load(&self.source(), &self.inputs_dir(), day)
+    }
+}
+
+/// Reads the input for `day` from `source`, adding the path to any error since a bare "No such
+/// file or directory" is not much help.
+pub fn load(source: &InputSource, inputs_dir: &Path, day: u8) -> io::Result<String> {
+    let path = match source {
+        InputSource::Real => input_path(inputs_dir, day, false),
+        InputSource::Example => input_path(inputs_dir, day, true),
+        InputSource::File(path) => path.clone(),
+        InputSource::Stdin => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input)?;
+            return Ok(input);
+        }
+        InputSource::Generated { size, seed } => {
+            return generate::generate(day, *seed, *size).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!("day {day} has no input generator"),
+                )
+            });
+        }
+    };
+    fs::read_to_string(&path)
+        .map_err(|err| io::Error::new(err.kind(), format!("{}: {err}", path.display())))
+}
@@ -0,0 +1,80 @@
+//! Library half of the journal. Each day's solution lives in its own module under `days`, and
+//! implements the [`Solution`] trait so that it can be driven by the binaries in `src/bin`, or
+//! called directly from other code.
+
+// The commentaries are written as prose rather than markdown, and wrapping every continuation
+// line of a list item to keep rustdoc happy would make them harder to read in the source.
+#![allow(clippy::doc_lazy_continuation)]
+
+pub mod answers;
+pub mod bench;
+pub mod cli;
+pub mod client;
+pub mod columns;
+pub mod days;
+pub mod differential;
+pub mod error;
+pub mod extract;
+pub mod generate;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod pool;
+pub mod registry;
+pub mod render;
+pub mod report;
+pub mod rng;
+pub mod runner;
+pub mod scaffold;
+pub mod table;
+
+use std::fmt::Display;
+
+use crate::error::{ParseError, Violation};
+use crate::runner::Part;
+
+/// The shape shared by every day's solution: parse the puzzle input once, then solve each part
+/// against the parsed representation.
+pub trait Solution {
+    /// Parsed representation of the puzzle input, shared by both parts.
+    type Input;
+    /// Type of the answer to each part, e.g. `u32` or `i64`.
+    type Answer: Display;
+
+    /// Parses the puzzle input, or reports the line (and column, where it can) of the first thing
+    /// that doesn't look like it should.
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part_one(input: &Self::Input) -> Self::Answer;
+
+    fn part_two(input: &Self::Input) -> Self::Answer;
+
+    /// Checks the assumptions the solution makes about its input, over and above it parsing,
+    /// and describes each one that `input` breaks, so that a wrong answer on an odd input is
+    /// explained rather than mysterious. An input which doesn't parse is left for `parse` to
+    /// report. Most days don't assume anything more, so there is nothing to check by default.
+    fn validate(_input: &str) -> Vec<Violation> {
+        Vec::new()
+    }
+
+    /// Other implementations of either part, which are checked against `part_one` and `part_two`
+    /// on generated inputs by the differential test, see [`differential`].
+    const ALTERNATIVES: &'static [Alternative] = &[];
+}
+
+/// The answer a new day's parts give until they're solved, see `src/days/day_xx.template`, so that
+/// `aoc run all` still runs. `aoc submit` won't send it.
+pub const UNSOLVED: &str = "unsolved";
+
+/// Another implementation of one part of a day, e.g. an earlier attempt or a different approach.
+#[derive(Clone, Copy, Debug)]
+pub struct Alternative {
+    /// Name to report it by, usually the function it runs.
+    pub name: &'static str,
+    pub part: Part,
+    /// Solves the part from the raw input, since an alternative may well parse it differently too.
+    pub solve: fn(&str) -> Result<String, ParseError>,
+    /// Kept for the record even though it's known to give the wrong answer on some inputs, so the
+    /// differential test expects it to disagree rather than failing on it.
+    pub known_wrong: bool,
+}
@@ -0,0 +1,72 @@
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+
+/// How many threads to use if not told otherwise: one per CPU.
+pub fn default_threads() -> usize {
+    thread::available_parallelism().map_or(1, |n| n.get())
+}
+
+/// Runs `f` on every item on a pool of `threads` worker threads, and returns the results in the
+/// same order as `items`. Workers take the next item as soon as they are free, so one slow item
+/// only ever holds up its own worker.
+///
+/// `on_done` is called on the calling thread as each item finishes, in whatever order they finish
+/// in, e.g. to report progress. A panic in `f` is caught and becomes that item's `Err`, holding
+/// the panic message, rather than taking the other items down with it.
+pub fn run<T, R>(
+    items: &[T],
+    threads: usize,
+    f: impl Fn(&T) -> R + Sync,
+    mut on_done: impl FnMut(usize, &Result<R, String>),
+) -> Vec<Result<R, String>>
+where
+    T: Sync,
+    R: Send,
+{
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+    let mut results: Vec<Option<Result<R, String>>> = items.iter().map(|_| None).collect();
+
+    thread::scope(|scope| {
+        for _ in 0..threads.clamp(1, items.len().max(1)) {
+            let sender = sender.clone();
+            let (next, f) = (&next, &f);
+            scope.spawn(move || loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(i) else {
+                    break;
+                };
+                let result = panic::catch_unwind(AssertUnwindSafe(|| f(item)))
+                    .map_err(|payload| panic_message(&*payload));
+                if sender.send((i, result)).is_err() {
+                    break;
+                }
+            });
+        }
+        // only the workers' senders are left, so the receiver hangs up once they are all done
+        drop(sender);
+        for (i, result) in receiver {
+            on_done(i, &result);
+            results[i] = Some(result);
+        }
+    });
+
+    results
+        .into_iter()
+        .map(|result| result.expect("every item should have been run"))
+        .collect()
+}
+
+/// The message a panic was started with, which is a `&str` or a `String` for `panic!` and friends.
+pub(crate) fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        String::from("unknown panic")
+    }
+}
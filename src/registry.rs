@@ -0,0 +1,98 @@
+use std::time::Duration;
+
+use crate::bench::{self, BenchConfig, DayBench, StageBench};
+use crate::days::*;
+use crate::error::{ParseError, Violation};
+use crate::report;
+use crate::runner::{self, DayRun, Part, PartRun};
+use crate::{Alternative, Solution};
+
+type SolveFn = fn(&str, &[Part]) -> Result<(Duration, Vec<PartRun>), ParseError>;
+type ValidateFn = fn(&str) -> Vec<Violation>;
+type BenchFn = fn(&str, &[Part], &BenchConfig) -> Result<Vec<StageBench>, ParseError>;
+
+/// A type-erased handle to one day's solution, so that days with different input and answer
+/// types can be looked up and run through the same table.
+pub struct Puzzle {
+    pub day: u8,
+    solve: SolveFn,
+    bench: BenchFn,
+    validate: ValidateFn,
+    /// See `Solution::ALTERNATIVES`.
+    pub alternatives: &'static [Alternative],
+}
+
+impl Puzzle {
+    /// The handle to `S`'s solution of `day`, see `puzzle!`.
+    pub const fn new<S: Solution>(day: u8) -> Puzzle {
+        Puzzle {
+            day,
+            solve: runner::run::<S>,
+            bench: bench::bench::<S>,
+            validate: S::validate,
+            alternatives: S::ALTERNATIVES,
+        }
+    }
+
+    /// Name matching the module, binary and input file, e.g. `day_06`.
+    pub fn name(&self) -> String {
+        format!("day_{:02}", self.day)
+    }
+
+    /// Solves `parts` of this day against `input`. A parse error is tagged with the day, which the
+    /// parsers themselves don't know.
+    pub fn run(&self, input: &str, parts: &[Part]) -> Result<DayRun, ParseError> {
+        let (parse_elapsed, parts) = (self.solve)(input, parts).map_err(|e| e.in_day(self.day))?;
+        Ok(DayRun {
+            day: self.day,
+            input_hash: report::input_hash(input),
+            parse_elapsed,
+            parts,
+        })
+    }
+
+    /// See `Solution::validate`. Like parse errors, the violations are tagged with the day.
+    pub fn validate(&self, input: &str) -> Vec<Violation> {
+        (self.validate)(input)
+            .into_iter()
+            .map(|violation| violation.in_day(self.day))
+            .collect()
+    }
+
+    pub fn bench(
+        &self,
+        input: &str,
+        parts: &[Part],
+        config: &BenchConfig,
+    ) -> Result<DayBench, ParseError> {
+        Ok(DayBench {
+            day: self.day,
+            input_hash: report::input_hash(input),
+            stages: (self.bench)(input, parts, config).map_err(|e| e.in_day(self.day))?,
+        })
+    }
+}
+
+macro_rules! puzzle {
+    ($day:literal, $solution:ty) => {
+        Puzzle::new::<$solution>($day)
+    };
+}
+
+/// Every day solved so far, in order.
+pub const PUZZLES: &[Puzzle] = &[
+    puzzle!(1, Day01),
+    puzzle!(2, Day02),
+    puzzle!(3, Day03),
+    puzzle!(4, Day04),
+    puzzle!(5, Day05),
+    puzzle!(6, Day06),
+    puzzle!(7, Day07),
+    puzzle!(8, Day08),
+    puzzle!(9, Day09),
+    puzzle!(11, Day11),
+];
+
+pub fn find(day: u8) -> Option<&'static Puzzle> {
+    PUZZLES.iter().find(|puzzle| puzzle.day == day)
+}
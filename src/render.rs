@@ -0,0 +1,270 @@
+//! Pictures of grid-based days: the puzzle's grid, with layers on top picking out whatever the
+//! solution found, e.g. the cells the day 06 guard visited. Saved as SVG, which keeps the grid's
+//! characters as text, or as PNG, which is just coloured squares.
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::days::{day_04, day_06, day_08};
+use crate::grid::Grid;
+
+/// An RGB colour, written `#rrggbb`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Color {
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Color {
+        Color { r, g, b }
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+}
+
+impl FromStr for Color {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let hex = s.strip_prefix('#').unwrap_or(s);
+        let channel = |i: usize| {
+            hex.get(i..i + 2)
+                .and_then(|channel| u8::from_str_radix(channel, 16).ok())
+        };
+        match (hex.len(), channel(0), channel(2), channel(4)) {
+            (6, Some(r), Some(g), Some(b)) => Ok(Color { r, g, b }),
+            _ => Err(format!("expected a colour like `#ff8800`, got `{s}`")),
+        }
+    }
+}
+
+/// A comma separated list of colours, e.g. `#ffffff,#cccccc`.
+pub fn parse_palette(s: &str) -> Result<Vec<Color>, String> {
+    s.split(',').map(|color| color.trim().parse()).collect()
+}
+
+/// Cells to fill in on top of the grid, each with the index of its colour in the palette, which
+/// wraps around if there are more shades than colours.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Layer {
+    /// Name to pick the layer by, e.g. for `--palette`.
+    pub name: &'static str,
+    pub palette: Vec<Color>,
+    pub cells: Vec<((usize, usize), usize)>,
+}
+
+impl Layer {
+    pub fn new(name: &'static str, palette: &[Color]) -> Layer {
+        Layer {
+            name,
+            palette: palette.to_vec(),
+            cells: Vec::new(),
+        }
+    }
+
+    pub fn mark(&mut self, pos: (usize, usize), shade: usize) {
+        self.cells.push((pos, shade));
+    }
+
+    fn color(&self, shade: usize) -> Color {
+        self.palette[shade % self.palette.len()]
+    }
+}
+
+/// Draws the layers of what a day's solution found on its grid.
+pub type LayersFn = fn(&Grid<char>) -> Vec<Layer>;
+
+/// The layers a day can draw over its grid, for every day which has any.
+pub fn day_layers(day: u8) -> Option<LayersFn> {
+    let layers: LayersFn = match day {
+        4 => day_04::layers,
+        6 => day_06::layers,
+        8 => day_08::layers,
+        _ => return None,
+    };
+    Some(layers)
+}
+
+/// Name of the grid's own palette: the colour of empty (`.`) cells, then of every other cell,
+/// then of the characters in SVGs.
+pub const GRID_PALETTE: &str = "grid";
+
+const DEFAULT_GRID_PALETTE: [Color; 3] = [
+    Color::rgb(0xff, 0xff, 0xff),
+    Color::rgb(0xd0, 0xd0, 0xd0),
+    Color::rgb(0x30, 0x30, 0x30),
+];
+
+/// A grid and the layers to draw on top of it, in order.
+#[derive(Clone, Debug)]
+pub struct Image<'a> {
+    pub grid: &'a Grid<char>,
+    pub palette: Vec<Color>,
+    pub layers: Vec<Layer>,
+    /// Width and height of each cell, in pixels.
+    pub cell_size: u32,
+}
+
+impl<'a> Image<'a> {
+    pub fn new(grid: &'a Grid<char>, layers: Vec<Layer>) -> Image<'a> {
+        Image {
+            grid,
+            palette: DEFAULT_GRID_PALETTE.to_vec(),
+            layers,
+            cell_size: 12,
+        }
+    }
+
+    /// Replaces the palette of the layer called `name`, or the grid's own palette for
+    /// [`GRID_PALETTE`].
+    pub fn set_palette(&mut self, name: &str, palette: Vec<Color>) -> Result<(), String> {
+        if palette.is_empty() {
+            return Err(format!("the palette for `{name}` has no colours"));
+        }
+        if name == GRID_PALETTE {
+            self.palette = palette;
+            return Ok(());
+        }
+        let layer = self.layers.iter_mut().find(|layer| layer.name == name);
+        let Some(layer) = layer else {
+            let names: Vec<&str> = self.layers.iter().map(|layer| layer.name).collect();
+            return Err(format!(
+                "there is no `{name}` layer, try one of {GRID_PALETTE}, {}",
+                names.join(", ")
+            ));
+        };
+        layer.palette = palette;
+        Ok(())
+    }
+
+    fn grid_color(&self, ch: char) -> Color {
+        let shade = if ch == '.' { 0 } else { 1 };
+        self.palette[shade.min(self.palette.len() - 1)]
+    }
+
+    /// Every cell's colour, i.e. the colour of the topmost layer which marks it, or of the grid.
+    fn colors(&self) -> Grid<Color> {
+        let mut colors = self.grid.map(|&ch| self.grid_color(ch));
+        for layer in &self.layers {
+            for &(pos, shade) in &layer.cells {
+                colors[pos] = layer.color(shade);
+            }
+        }
+        colors
+    }
+
+    /// The image as an SVG, with a rectangle per cell that isn't the background, in a group per
+    /// layer, and then the grid's characters on top.
+    pub fn to_svg(&self) -> String {
+        let size = self.cell_size;
+        let (width, height) = (
+            self.grid.cols() as u32 * size,
+            self.grid.rows() as u32 * size,
+        );
+        let rect = |(row, col): (usize, usize), color: Color| {
+            format!(
+                "    <rect x=\"{}\" y=\"{}\" width=\"{size}\" height=\"{size}\" fill=\"{color}\"/>\n",
+                col as u32 * size,
+                row as u32 * size
+            )
+        };
+
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" viewBox=\"0 0 {width} {height}\">\n"
+        );
+        svg.push_str(&format!(
+            "  <rect width=\"{width}\" height=\"{height}\" fill=\"{}\"/>\n",
+            self.grid_color('.')
+        ));
+        svg.push_str("  <g id=\"grid\">\n");
+        for (pos, &ch) in self.grid.iter().filter(|(_, &ch)| ch != '.') {
+            svg.push_str(&rect(pos, self.grid_color(ch)));
+        }
+        svg.push_str("  </g>\n");
+        for layer in &self.layers {
+            svg.push_str(&format!("  <g id=\"{}\">\n", layer.name));
+            for &(pos, shade) in &layer.cells {
+                svg.push_str(&rect(pos, layer.color(shade)));
+            }
+            svg.push_str("  </g>\n");
+        }
+
+        let text_color = self
+            .palette
+            .get(2)
+            .copied()
+            .unwrap_or(DEFAULT_GRID_PALETTE[2]);
+        svg.push_str(&format!(
+            "  <g id=\"text\" font-family=\"monospace\" font-size=\"{}\" text-anchor=\"middle\" dominant-baseline=\"central\" fill=\"{text_color}\">\n",
+            size * 3 / 4
+        ));
+        for ((row, col), &ch) in self.grid.iter().filter(|(_, &ch)| ch != '.') {
+            let text = match ch {
+                '<' => String::from("&lt;"),
+                '>' => String::from("&gt;"),
+                '&' => String::from("&amp;"),
+                ch => ch.to_string(),
+            };
+            svg.push_str(&format!(
+                "    <text x=\"{}\" y=\"{}\">{text}</text>\n",
+                col as u32 * size + size / 2,
+                row as u32 * size + size / 2
+            ));
+        }
+        svg.push_str("  </g>\n</svg>\n");
+        svg
+    }
+
+    /// The image as a PNG, where each cell is a square of its colour. Fails if the image has no
+    /// pixels, i.e. the grid is empty or the cells are 0 pixels wide, which PNG doesn't allow.
+    pub fn to_png(&self) -> io::Result<Vec<u8>> {
+        let size = self.cell_size as usize;
+        let (width, height) = (self.grid.cols() * size, self.grid.rows() * size);
+        let colors = self.colors();
+        let mut pixels = Vec::with_capacity(width * height * 3);
+        for y in 0..height {
+            for x in 0..width {
+                let color = colors[(y / size, x / size)];
+                pixels.extend([color.r, color.g, color.b]);
+            }
+        }
+
+        // writing to a Vec can't fail, so any error is down to the size of the image
+        let invalid = |err: png::EncodingError| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("can't make a {width}x{height} PNG: {err}"),
+            )
+        };
+        let mut png = Vec::new();
+        let mut encoder = png::Encoder::new(&mut png, width as u32, height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header().map_err(invalid)?;
+        writer.write_image_data(&pixels).map_err(invalid)?;
+        writer.finish().map_err(invalid)?;
+        Ok(png)
+    }
+
+    /// Saves the image to `path`, as an SVG or PNG depending on its extension.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let extension = path.extension().and_then(|ext| ext.to_str());
+        match extension.map(str::to_ascii_lowercase).as_deref() {
+            Some("svg") => fs::write(path, self.to_svg()),
+            Some("png") => fs::write(path, self.to_png()?),
+            _ => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("{}: expected a .svg or .png file name", path.display()),
+            )),
+        }
+    }
+}
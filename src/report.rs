@@ -0,0 +1,168 @@
+use clap::ValueEnum;
+
+use crate::runner::DayRun;
+
+/// How `aoc run` prints its results.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// An aligned table, for people
+    #[default]
+    Table,
+    /// A JSON array with one object per day and part, for other tools
+    Json,
+    /// CSV with one row per day and part, for spreadsheets
+    Csv,
+}
+
+/// Hash of an input's contents, so that results from different runs can be told apart when they
+/// weren't run against the same input. This is 64-bit FNV-1a, since the std hasher makes no
+/// promise to stay the same between Rust versions.
+pub fn input_hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    });
+    format!("{hash:016x}")
+}
+
+/// The commit the binary was built from, with `-dirty` on the end if there were uncommitted
+/// changes, or `None` if it wasn't built from a git checkout. This is captured by `build.rs`
+/// rather than asked of git at run time, so that an old binary doesn't claim to be the commit
+/// that happens to be checked out now.
+pub const GIT_REVISION: Option<&str> = option_env!("AOC_GIT_REVISION");
+
+/// Everything known about solving one part of one day, flattened so that it can be written out
+/// as a row or an object.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    /// Which input was solved, as in the answers file, e.g. `real` or `example`.
+    pub input: String,
+    pub answer: String,
+    pub expected: Option<String>,
+    pub status: String,
+    /// Shared by both parts of a day, so it is repeated in each of their records.
+    pub parse_nanos: u128,
+    pub solve_nanos: u128,
+    pub input_hash: String,
+    pub git_revision: Option<String>,
+}
+
+const FIELDS: [&str; 10] = [
+    "day",
+    "part",
+    "input",
+    "answer",
+    "expected",
+    "status",
+    "parse_ns",
+    "solve_ns",
+    "input_hash",
+    "git_revision",
+];
+
+/// One record for every part of every run.
+pub fn records(runs: &[DayRun], input: &str, git_revision: Option<&str>) -> Vec<Record> {
+    runs.iter()
+        .flat_map(|run| {
+            run.parts.iter().map(move |part_run| Record {
+                day: run.day,
+                part: part_run.part.number(),
+                input: input.to_string(),
+                answer: part_run.answer.clone(),
+                expected: part_run.expected.clone(),
+                status: part_run.status().to_string(),
+                parse_nanos: run.parse_elapsed.as_nanos(),
+                solve_nanos: part_run.elapsed.as_nanos(),
+                input_hash: run.input_hash.clone(),
+                git_revision: git_revision.map(String::from),
+            })
+        })
+        .collect()
+}
+
+/// The fields of `record` in the order of [`FIELDS`], as JSON values.
+fn json_values(record: &Record) -> [String; 10] {
+    let string = |s: &str| format!("\"{}\"", escape_json(s));
+    let optional = |s: &Option<String>| s.as_deref().map_or(String::from("null"), string);
+    [
+        record.day.to_string(),
+        record.part.to_string(),
+        string(&record.input),
+        string(&record.answer),
+        optional(&record.expected),
+        string(&record.status),
+        record.parse_nanos.to_string(),
+        record.solve_nanos.to_string(),
+        string(&record.input_hash),
+        optional(&record.git_revision),
+    ]
+}
+
+fn escape_json(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for ch in s.chars() {
+        match ch {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            ch if ch.is_control() => escaped.push_str(&format!("\\u{:04x}", ch as u32)),
+            ch => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+/// The records as a JSON array of objects, one per line.
+pub fn to_json(records: &[Record]) -> String {
+    let objects: Vec<String> = records
+        .iter()
+        .map(|record| {
+            let members: Vec<String> = FIELDS
+                .iter()
+                .zip(json_values(record))
+                .map(|(field, value)| format!("\"{field}\": {value}"))
+                .collect();
+            format!("  {{{}}}", members.join(", "))
+        })
+        .collect();
+    if objects.is_empty() {
+        String::from("[]\n")
+    } else {
+        format!("[\n{}\n]\n", objects.join(",\n"))
+    }
+}
+
+/// Quotes a CSV field if it needs it, doubling any quotes inside.
+fn escape_csv(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// The records as CSV, with a header row. A missing expected answer or revision is left empty.
+pub fn to_csv(records: &[Record]) -> String {
+    let mut csv = FIELDS.join(",");
+    csv.push('\n');
+    for record in records {
+        let fields = [
+            record.day.to_string(),
+            record.part.to_string(),
+            escape_csv(&record.input),
+            escape_csv(&record.answer),
+            escape_csv(record.expected.as_deref().unwrap_or_default()),
+            record.status.clone(),
+            record.parse_nanos.to_string(),
+            record.solve_nanos.to_string(),
+            record.input_hash.clone(),
+            escape_csv(record.git_revision.as_deref().unwrap_or_default()),
+        ];
+        csv.push_str(&fields.join(","));
+        csv.push('\n');
+    }
+    csv
+}
@@ -0,0 +1,151 @@
+use std::fmt;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use crate::answers::Status;
+use crate::error::ParseError;
+use crate::table;
+use crate::Solution;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("expected part 1 or 2, got `{s}`")),
+        }
+    }
+}
+
+/// The answer to a single part, and how long it took to solve (excluding parsing).
+#[derive(Clone, Debug)]
+pub struct PartRun {
+    pub part: Part,
+    pub answer: String,
+    /// The answer on record for this input, see `Answers::annotate`.
+    pub expected: Option<String>,
+    pub elapsed: Duration,
+}
+
+impl PartRun {
+    pub fn status(&self) -> Status {
+        match &self.expected {
+            None => Status::Unknown,
+            Some(expected) if *expected == self.answer => Status::Pass,
+            Some(_) => Status::Fail,
+        }
+    }
+}
+
+/// Everything we learnt from running a single day: how long the input took to parse, and the
+/// result of each part that was asked for.
+#[derive(Clone, Debug)]
+pub struct DayRun {
+    pub day: u8,
+    /// See `report::input_hash`.
+    pub input_hash: String,
+    pub parse_elapsed: Duration,
+    pub parts: Vec<PartRun>,
+}
+
+impl DayRun {
+    pub fn has_failures(&self) -> bool {
+        self.parts.iter().any(|p| p.status() == Status::Fail)
+    }
+
+    pub fn total_elapsed(&self) -> Duration {
+        self.parse_elapsed + self.parts.iter().map(|p| p.elapsed).sum::<Duration>()
+    }
+}
+
+/// Parses `input` once, then solves each of `parts` against it, timing every step separately.
+/// The answers are stringified here so that days with different answer types can sit side by
+/// side in the registry.
+pub fn run<S: Solution>(
+    input: &str,
+    parts: &[Part],
+) -> Result<(Duration, Vec<PartRun>), ParseError> {
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    let parse_elapsed = start.elapsed();
+
+    let part_runs = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = match part {
+                Part::One => S::part_one(&parsed),
+                Part::Two => S::part_two(&parsed),
+            };
+            let elapsed = start.elapsed();
+            PartRun {
+                part,
+                answer: answer.to_string(),
+                expected: None,
+                elapsed,
+            }
+        })
+        .collect();
+
+    Ok((parse_elapsed, part_runs))
+}
+
+/// Renders the runs as an aligned table of answers, how they compare to the expected answers, and
+/// timings, with a total at the bottom. The parse time is only shown against the first part of
+/// each day, since both parts share it.
+pub fn format_table(runs: &[DayRun]) -> String {
+    let header = ["Day", "Part", "Answer", "Status", "Parse", "Solve"];
+    let mut rows: Vec<Vec<String>> = Vec::new();
+    for run in runs {
+        for (i, part_run) in run.parts.iter().enumerate() {
+            let parse = if i == 0 {
+                format!("{:.2?}", run.parse_elapsed)
+            } else {
+                String::new()
+            };
+            let status = match (part_run.status(), &part_run.expected) {
+                (Status::Fail, Some(expected)) => format!("FAIL (expected {expected})"),
+                (status, _) => status.to_string(),
+            };
+            rows.push(vec![
+                format!("{:02}", run.day),
+                part_run.part.to_string(),
+                part_run.answer.clone(),
+                status,
+                parse,
+                format!("{:.2?}", part_run.elapsed),
+            ]);
+        }
+    }
+
+    let mut table = table::render(&header, &rows);
+
+    let total: Duration = runs.iter().map(DayRun::total_elapsed).sum();
+    table.push_str(&format!("Total: {total:.2?}\n"));
+    table
+}
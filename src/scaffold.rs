@@ -0,0 +1,146 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::input;
+
+/// Starting point for a new day's module. `{day}` and `{day:02}` are filled in with the day.
+const MODULE_TEMPLATE: &str = include_str!("days/day_xx.template");
+
+/// Creates everything needed to start on a new day, and registers it so that it can be run
+/// straight away:
+/// - the solution module `src/days/day_XX.rs`, from `src/days/day_xx.template`
+/// - the day binary `src/bin/day_XX.rs`
+/// - empty `day_XX.txt` and `day_XX_test.txt` inputs in `inputs_dir`, unless they already exist
+/// - an entry in `src/days/mod.rs` and in the registry, which is what `aoc` and the example test
+///   suite run
+///
+/// `root` is the root of the crate. Returns every file created or changed.
+pub fn new_day(root: &Path, inputs_dir: &Path, day: u8) -> io::Result<Vec<PathBuf>> {
+    if !(1..=25).contains(&day) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("there is no day {day}, Advent of Code runs from day 1 to 25"),
+        ));
+    }
+    let name = format!("day_{day:02}");
+    let module_path = root.join("src").join("days").join(format!("{name}.rs"));
+    if module_path.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", module_path.display()),
+        ));
+    }
+
+    // work out the registrations before creating anything, so that a mod.rs or registry which
+    // can't be read stops it before there's anything to clean up
+    let mod_path = root.join("src").join("days").join("mod.rs");
+    let mut mod_rs = fs::read_to_string(&mod_path)?;
+    mod_rs = insert_in_order(&mod_rs, day, &format!("pub mod {name};"), |line| {
+        day_of(line, "pub mod day_", ";")
+    });
+    mod_rs = insert_in_order(
+        &mod_rs,
+        day,
+        &format!("pub use {name}::Day{day:02};"),
+        |line| day_of(line, "pub use day_", "::"),
+    );
+
+    let registry_path = root.join("src").join("registry.rs");
+    let registry = fs::read_to_string(&registry_path)?;
+    let registry = insert_in_order(
+        &registry,
+        day,
+        &format!("    puzzle!({day}, Day{day:02}),"),
+        |line| day_of(line.trim_start(), "puzzle!(", ","),
+    );
+
+    let mut touched = Vec::new();
+
+    let module = MODULE_TEMPLATE
+        .replace("{day:02}", &format!("{day:02}"))
+        .replace("{day}", &day.to_string());
+    fs::write(&module_path, module)?;
+    touched.push(module_path);
+
+    let bin_path = root.join("src").join("bin").join(format!("{name}.rs"));
+    fs::write(&bin_path, bin_source(day))?;
+    touched.push(bin_path);
+
+    fs::create_dir_all(inputs_dir)?;
+    for example in [false, true] {
+        let path = input::input_path(inputs_dir, day, example);
+        if !path.exists() {
+            fs::write(&path, "")?;
+            touched.push(path);
+        }
+    }
+
+    write_all_or_nothing(&[(&mod_path, &mod_rs), (&registry_path, &registry)])?;
+    touched.extend([mod_path, registry_path]);
+
+    Ok(touched)
+}
+
+/// Writes every file, or none of them if any can't be written: each goes to a temporary file next
+/// to it first, and they're only renamed over the originals once all of them are written. A
+/// rename replaces the file in one go, so the crate is never left with a day in mod.rs but not in
+/// the registry, or with half a registry.
+fn write_all_or_nothing(files: &[(&Path, &str)]) -> io::Result<()> {
+    let temp_path = |path: &Path| path.with_extension("rs.tmp");
+    for (i, &(path, text)) in files.iter().enumerate() {
+        if let Err(err) = fs::write(temp_path(path), text) {
+            for &(path, _) in &files[..=i] {
+                let _ = fs::remove_file(temp_path(path));
+            }
+            return Err(err);
+        }
+    }
+    for &(path, _) in files {
+        fs::rename(temp_path(path), path)?;
+    }
+    Ok(())
+}
+
+fn bin_source(day: u8) -> String {
+    format!(
+        "use std::process::ExitCode;
+
+// e.g. `cargo run --bin day_{day:02} -- --example`, see `--help` for the other ways to pick an input
+fn main() -> ExitCode {{
+    aoc_2024::cli::day_main({day})
+}}
+"
+    )
+}
+
+/// The day in `line` between `prefix` and `suffix`, e.g. 6 in `pub mod day_06;`.
+fn day_of(line: &str, prefix: &str, suffix: &str) -> Option<u8> {
+    let rest = line.strip_prefix(prefix)?;
+    let end = rest.find(suffix)?;
+    rest[..end].parse().ok()
+}
+
+/// Inserts `new_line` into the run of lines that `day_of` recognises, keeping them ordered by
+/// day, i.e. before the first later day, or otherwise after the last day.
+fn insert_in_order(
+    text: &str,
+    day: u8,
+    new_line: &str,
+    day_of: impl Fn(&str) -> Option<u8>,
+) -> String {
+    let mut lines: Vec<&str> = text.lines().collect();
+    let days: Vec<(usize, u8)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| Some((i, day_of(line)?)))
+        .collect();
+    let position = match days.iter().find(|&&(_, other)| other > day) {
+        Some(&(i, _)) => i,
+        None => days.last().map_or(lines.len(), |&(i, _)| i + 1),
+    };
+    lines.insert(position, new_line);
+    let mut text = lines.join("\n");
+    text.push('\n');
+    text
+}
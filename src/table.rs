@@ -0,0 +1,26 @@
+/// Renders `rows` under `header` as left-aligned columns separated by two spaces, for the
+/// reports printed by the `aoc` binary.
+pub fn render(header: &[&str], rows: &[Vec<String>]) -> String {
+    let mut widths: Vec<usize> = header.iter().map(|cell| cell.len()).collect();
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+
+    let mut table = String::new();
+    let mut push_row = |cells: &[&str]| {
+        let line: Vec<String> = cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, &width)| format!("{cell:<width$}"))
+            .collect();
+        table.push_str(line.join("  ").trim_end());
+        table.push('\n');
+    };
+    push_row(header);
+    for row in rows {
+        push_row(&row.iter().map(String::as_str).collect::<Vec<_>>());
+    }
+    table
+}
@@ -0,0 +1,56 @@
+use std::time::Duration;
+
+use aoc_2024::bench::{Baseline, DayBench, Stage, StageBench, Stats};
+
+fn day_bench(input_hash: &str, median_ns: u64) -> DayBench {
+    DayBench {
+        day: 1,
+        input_hash: input_hash.to_string(),
+        stages: vec![StageBench {
+            stage: Stage::Parse,
+            stats: Stats {
+                samples: 20,
+                iterations_per_sample: 1,
+                median: Duration::from_nanos(median_ns),
+                p95: Duration::from_nanos(median_ns),
+            },
+        }],
+    }
+}
+
+fn compare(baseline: &Baseline, bench: &DayBench) -> Option<String> {
+    let stage = &bench.stages[0];
+    baseline.compare(bench.day, stage.stage, &bench.input_hash, &stage.stats)
+}
+
+#[test]
+fn baselines_only_compare_the_same_input() {
+    let mut baseline = Baseline::default();
+    baseline.update(&[day_bench("real", 1000)]);
+
+    let same = day_bench("real", 1500);
+    assert_eq!(
+        compare(&baseline, &same).as_deref(),
+        Some("+50.0% (regressed)")
+    );
+    assert!(baseline.different_inputs(&[same]).is_empty());
+
+    let generated = day_bench("generated", 100);
+    assert_eq!(
+        compare(&baseline, &generated).as_deref(),
+        Some("different input")
+    );
+    assert_eq!(baseline.different_inputs(&[generated]), [1]);
+}
+
+#[test]
+fn a_zero_baseline_median_is_not_compared() {
+    let mut baseline = Baseline::default();
+    baseline.update(&[day_bench("real", 0)]);
+    for median_ns in [0, 5] {
+        assert_eq!(
+            compare(&baseline, &day_bench("real", median_ns)).as_deref(),
+            Some("too quick to compare")
+        );
+    }
+}
@@ -0,0 +1,184 @@
+mod common;
+
+use std::fs;
+use std::time::{Duration, Instant};
+
+use aoc_2024::answers::Guesses;
+use aoc_2024::client::{self, Client, ClientError, Fetched, Throttle, Verdict};
+use aoc_2024::input;
+use aoc_2024::runner::Part;
+use common::StubServer;
+
+fn stub_client(server: &StubServer, name: &str) -> Client {
+    let stamp = common::temp_dir(name).join("last-request");
+    Client::new(
+        &server.url,
+        String::from("53cr3t"),
+        Throttle::new(stamp, Duration::ZERO),
+    )
+}
+
+#[test]
+fn fetch_downloads_once_then_uses_the_cache() {
+    let server = StubServer::start(vec![(200, "3   4\n4   3\n")]);
+    let client = stub_client(&server, "fetch-cache-stamp");
+    let inputs_dir = common::temp_dir("fetch-cache");
+    let path = input::input_path(&inputs_dir, 1, false);
+
+    let fetched = client::fetch_input(&client, &inputs_dir, 1).unwrap();
+    assert_eq!(fetched, Fetched::Downloaded(path.clone()));
+    assert_eq!(fs::read_to_string(&path).unwrap(), "3   4\n4   3\n");
+
+    let fetched = client::fetch_input(&client, &inputs_dir, 1).unwrap();
+    assert_eq!(fetched, Fetched::Cached(path));
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1, "the cached input was downloaded again");
+    let request = requests[0].to_lowercase();
+    assert!(request.starts_with("get /2024/day/1/input "), "{request}");
+    assert!(request.contains("cookie: session=53cr3t"), "{request}");
+    assert!(
+        request.contains("user-agent: github.com/cy6581/aoc_2024_rs"),
+        "{request}"
+    );
+}
+
+#[test]
+fn fetch_replaces_an_empty_scaffolded_input() {
+    let server = StubServer::start(vec![(200, "125 17\n")]);
+    let client = stub_client(&server, "fetch-empty-stamp");
+    let inputs_dir = common::temp_dir("fetch-empty");
+    let path = input::input_path(&inputs_dir, 11, false);
+    fs::write(&path, "").unwrap();
+
+    let fetched = client::fetch_input(&client, &inputs_dir, 11).unwrap();
+    assert_eq!(fetched, Fetched::Downloaded(path.clone()));
+    assert_eq!(fs::read_to_string(&path).unwrap(), "125 17\n");
+    assert!(!path.with_extension("txt.partial").exists());
+}
+
+#[test]
+fn a_download_that_cant_be_saved_leaves_no_input() {
+    let server = StubServer::start(vec![(200, "125 17\n")]);
+    let client = stub_client(&server, "fetch-blocked-stamp");
+    let inputs_dir = common::temp_dir("fetch-blocked");
+    // a directory where the download is written first means it can't be saved at all
+    let path = input::input_path(&inputs_dir, 11, false);
+    fs::create_dir_all(path.with_extension("txt.partial").join("in-the-way")).unwrap();
+
+    assert!(client::fetch_input(&client, &inputs_dir, 11).is_err());
+    assert!(!path.exists());
+    assert_eq!(client::cached_input(&inputs_dir, 11), None);
+}
+
+#[test]
+fn failed_download_saves_nothing() {
+    let server = StubServer::start(vec![(
+        404,
+        "Please don't repeatedly request this endpoint before it unlocks!",
+    )]);
+    let client = stub_client(&server, "fetch-404-stamp");
+    let inputs_dir = common::temp_dir("fetch-404");
+
+    let err = client::fetch_input(&client, &inputs_dir, 25).unwrap_err();
+    assert!(matches!(err, ClientError::Status { code: 404, .. }));
+    assert_eq!(
+        err.to_string(),
+        "the site responded with 404: Please don't repeatedly request this endpoint before it unlocks!"
+    );
+    assert_eq!(client::cached_input(&inputs_dir, 25), None);
+}
+
+#[test]
+fn throttle_spaces_out_requests() {
+    let stamp = common::temp_dir("throttle").join("last-request");
+    let throttle = Throttle::new(stamp, Duration::from_millis(200));
+    throttle.wait().unwrap();
+    let start = Instant::now();
+    throttle.wait().unwrap();
+    assert!(start.elapsed() >= Duration::from_millis(150));
+}
+
+#[test]
+fn throttle_holds_across_concurrent_waiters() {
+    // each thread opens the stamp file itself, like separate `aoc` processes would
+    let stamp = common::temp_dir("throttle-concurrent").join("last-request");
+    let interval = Duration::from_millis(100);
+    let waiters: Vec<_> = (0..4)
+        .map(|_| {
+            let throttle = Throttle::new(stamp.clone(), interval);
+            std::thread::spawn(move || {
+                throttle.wait().unwrap();
+                Instant::now()
+            })
+        })
+        .collect();
+    let mut times: Vec<Instant> = waiters.into_iter().map(|w| w.join().unwrap()).collect();
+    times.sort();
+    for pair in times.windows(2) {
+        assert!(pair[1] - pair[0] >= Duration::from_millis(80), "{times:?}");
+    }
+}
+
+fn response_page(article: &str) -> String {
+    format!("<html><body><header>[Log In]</header><main><article><p>{article}</p></article></main></body></html>")
+}
+
+#[test]
+fn verdicts_are_read_from_the_response() {
+    let cases = [
+        ("That's the right answer! You are <em>one gold star</em> closer.", Verdict::Correct),
+        ("That's not the right answer; your answer is too high. Please wait one minute before trying again.", Verdict::TooHigh),
+        ("That's not the right answer; your answer is too low.", Verdict::TooLow),
+        ("That's not the right answer. If you're stuck, make sure you're using the full input data.", Verdict::Wrong),
+        (
+            "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 37s left to wait. <a href=\"/2024/day/6\">[Return to Day 6]</a>",
+            Verdict::RateLimited { wait: Some(String::from("37s")) },
+        ),
+        ("You don't seem to be solving the right level.  Did you already complete it?", Verdict::AlreadySolved),
+        ("Something <em>else</em>  entirely", Verdict::Unrecognised(String::from("Something else entirely"))),
+    ];
+    for (article, verdict) in cases {
+        assert_eq!(
+            Verdict::parse(&response_page(article)),
+            verdict,
+            "{article}"
+        );
+    }
+}
+
+#[test]
+fn submit_posts_the_answer_as_a_form() {
+    let page = response_page("That's not the right answer; your answer is too low.");
+    let server = StubServer::start(vec![(200, page)]);
+    let client = stub_client(&server, "submit-stamp");
+
+    let verdict = client.submit(6, Part::Two, "1686").unwrap();
+    assert_eq!(verdict, Verdict::TooLow);
+    let request = &server.requests()[0];
+    assert!(request.starts_with("POST /2024/day/6/answer "), "{request}");
+    assert!(request.ends_with("level=2&answer=1686"), "{request}");
+}
+
+#[test]
+fn wrong_guesses_rule_out_answers_and_survive_a_reload() {
+    let inputs_dir = common::temp_dir("guesses");
+    let mut guesses = Guesses::load(&inputs_dir).unwrap();
+    assert_eq!(guesses.rule_out(6, Part::Two, "1686"), None);
+
+    assert!(guesses.record(6, Part::Two, "2000", &Verdict::TooHigh));
+    assert!(guesses.record(6, Part::Two, "1000", &Verdict::TooLow));
+    assert!(guesses.record(6, Part::Two, "1500", &Verdict::Wrong));
+    assert!(!guesses.record(6, Part::Two, "1686", &Verdict::Correct));
+    guesses.save().unwrap();
+
+    let guesses = Guesses::load(&inputs_dir).unwrap();
+    for ruled_out in ["2000", "2500", "1000", "999", "1500"] {
+        assert!(
+            guesses.rule_out(6, Part::Two, ruled_out).is_some(),
+            "{ruled_out}"
+        );
+    }
+    assert_eq!(guesses.rule_out(6, Part::Two, "1686"), None);
+    assert_eq!(guesses.rule_out(6, Part::One, "2500"), None);
+}
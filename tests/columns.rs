@@ -0,0 +1,63 @@
+use aoc_2024::columns::{LInf, Metric, Pairwise, Rank, Table, L1, L2};
+use aoc_2024::days::Day01;
+use aoc_2024::Solution;
+
+#[test]
+fn tables_take_any_number_of_columns() {
+    let table: Table<u32> = Table::parse("1 2 3\n\n4  5\t6\n", None).unwrap();
+    assert_eq!((table.width(), table.rows()), (3, 2));
+    assert_eq!(table.column(1), [2, 5]);
+
+    let err = Table::<u32>::parse("1 2 3\n4 5\n", None).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "line 2: expected 3 numbers like the first line, found 2"
+    );
+    let err = Table::<u32>::parse("1 2 3\n", Some(2)).unwrap_err();
+    assert_eq!(err.to_string(), "line 1: expected 2 numbers, found 3");
+    let err = Table::<i32>::parse("1 -2\n3 four\n", None).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "line 2, column 3: expected a number, found `four`"
+    );
+}
+
+#[test]
+fn l1_is_day_01_part_one() {
+    let input = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+    let table: Table<u32> = Table::parse(input, None).unwrap();
+    let part_one = Day01::part_one(&Day01::parse(input).unwrap());
+    assert_eq!(
+        table.pairwise(&L1),
+        [Pairwise {
+            left: 0,
+            right: 1,
+            distance: part_one as f64
+        }]
+    );
+}
+
+#[test]
+fn metrics_measure_different_things() {
+    // sorted, the columns differ by 1, 2 and 2, but the second has the rows in reverse order
+    let (a, b) = ([1.0, 2.0, 3.0], [5.0, 4.0, 2.0]);
+    assert_eq!(L1.distance(&a, &b), 5.0);
+    assert_eq!(L2.distance(&a, &b), 3.0);
+    assert_eq!(LInf.distance(&a, &b), 2.0);
+    assert_eq!(Rank.distance(&a, &b), 4.0);
+    // the same order, however far apart the numbers are
+    assert_eq!(Rank.distance(&a, &[10.0, 20.0, 30.0]), 0.0);
+    // ties share their rank, whichever order they're in
+    assert_eq!(Rank.distance(&[1.0, 1.0, 2.0], &[5.0, 5.0, 9.0]), 0.0);
+}
+
+#[test]
+fn pairwise_covers_every_pair_of_columns() {
+    let table: Table<i32> = Table::parse("1 1 -1\n2 3 -2\n", None).unwrap();
+    let distances: Vec<(usize, usize, f64)> = table
+        .pairwise(&LInf)
+        .iter()
+        .map(|pair| (pair.left, pair.right, pair.distance))
+        .collect();
+    assert_eq!(distances, [(0, 1, 1.0), (0, 2, 3.0), (1, 2, 4.0)]);
+}
@@ -0,0 +1,96 @@
+//! Helpers shared by the integration tests.
+
+// every test binary compiles its own copy of this module, and most only use some of it
+#![allow(dead_code)]
+
+use std::env;
+use std::fs;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// A fresh, empty directory under the system temp dir, unique to this process and `name`.
+pub fn temp_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("aoc_2024-{}-{name}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).expect("temp dir should be writable");
+    dir
+}
+
+/// Stands in for the site: a local HTTP server which answers each connection with the next of
+/// its canned responses, and remembers the requests it was sent.
+pub struct StubServer {
+    pub url: String,
+    requests: Arc<Mutex<Vec<String>>>,
+}
+
+impl StubServer {
+    pub fn start(responses: Vec<(u16, impl Into<String>)>) -> StubServer {
+        let responses: Vec<(u16, String)> = responses
+            .into_iter()
+            .map(|(code, body)| (code, body.into()))
+            .collect();
+        let listener = TcpListener::bind("127.0.0.1:0").expect("stub server should bind");
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let seen = Arc::clone(&requests);
+        thread::spawn(move || {
+            for (code, body) in responses {
+                let (stream, _) = listener.accept().expect("stub server should accept");
+                let mut reader = BufReader::new(stream);
+                let mut request = String::new();
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some((name, value)) = line.split_once(':') {
+                        if name.eq_ignore_ascii_case("content-length") {
+                            content_length = value.trim().parse().unwrap();
+                        }
+                    }
+                    request.push_str(&line);
+                    if line == "\r\n" || line.is_empty() {
+                        break;
+                    }
+                }
+                let mut content = vec![0; content_length];
+                reader.read_exact(&mut content).unwrap();
+                request.push_str(&String::from_utf8_lossy(&content));
+                seen.lock().unwrap().push(request);
+
+                let response = format!(
+                    "HTTP/1.1 {code} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                reader.get_mut().write_all(response.as_bytes()).unwrap();
+            }
+        });
+        StubServer { url, requests }
+    }
+
+    /// Every request received so far, headers and body.
+    pub fn requests(&self) -> Vec<String> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+/// Every day 02 report of `len` levels between 1 and `max`, e.g. all 36 of `[1, 1]` to `[6, 6]`
+/// for a `len` of 2 and a `max` of 6.
+pub fn reports(len: usize, max: u32) -> Vec<Vec<u32>> {
+    let mut reports = vec![Vec::new()];
+    for _ in 0..len {
+        reports = reports
+            .into_iter()
+            .flat_map(|report| {
+                (1..=max).map(move |level| {
+                    let mut report = report.clone();
+                    report.push(level);
+                    report
+                })
+            })
+            .collect();
+    }
+    reports
+}
@@ -0,0 +1,59 @@
+mod common;
+
+use aoc_2024::days::day_02;
+
+fn is_safe(row: &[u32]) -> bool {
+    let steps = |ok: fn(u32, u32) -> bool| row.windows(2).all(|w| ok(w[0], w[1]));
+    row.len() >= 2 && (steps(|a, b| b > a && b - a <= 3) || steps(|a, b| a > b && a - b <= 3))
+}
+
+/// Tries every way of keeping all but at most `k` levels.
+fn brute_force(row: &[u32], k: usize) -> bool {
+    (0u32..1 << row.len())
+        .filter(|kept| row.len() - kept.count_ones() as usize <= k)
+        .any(|kept| {
+            let left: Vec<u32> = (0..row.len())
+                .filter(|&i| kept & (1 << i) != 0)
+                .map(|i| row[i])
+                .collect();
+            is_safe(&left)
+        })
+}
+
+#[test]
+fn one_removal_agrees_with_the_dampener() {
+    for len in 2..=6 {
+        for report in common::reports(len, 6) {
+            assert_eq!(
+                day_02::is_safe_with_removals(&report, 1),
+                day_02::is_safe_with_dampener(&report),
+                "{report:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn any_number_of_removals_agrees_with_brute_force() {
+    for len in 2..=6 {
+        for report in common::reports(len, 5) {
+            for k in 0..=3 {
+                assert_eq!(
+                    day_02::is_safe_with_removals(&report, k),
+                    brute_force(&report, k),
+                    "{report:?} k = {k}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn removals_scale_to_long_reports() {
+    // a long climb with a bad level every 1000, so it takes exactly one removal per bad level
+    let report: Vec<u32> = (0..100_000u32)
+        .map(|i| if i % 1000 == 500 { 0 } else { i + 1 })
+        .collect();
+    assert!(!day_02::is_safe_with_removals(&report, 99));
+    assert!(day_02::is_safe_with_removals(&report, 100));
+}
@@ -0,0 +1,73 @@
+use aoc_2024::registry::{self, PUZZLES};
+use aoc_2024::{differential, generate};
+
+const SEEDS: u64 = 10;
+const MAX_SIZE: usize = 30;
+
+#[test]
+fn alternatives_agree_with_the_solution() {
+    let mut failures = Vec::new();
+    for puzzle in PUZZLES {
+        for alternative in puzzle.alternatives.iter().filter(|alt| !alt.known_wrong) {
+            if let Some(disagreement) = differential::search(puzzle, alternative, SEEDS, MAX_SIZE) {
+                failures.push(disagreement.to_string());
+            }
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n\n"));
+}
+
+#[test]
+fn known_wrong_alternatives_are_caught() {
+    for puzzle in PUZZLES {
+        for alternative in puzzle.alternatives.iter().filter(|alt| alt.known_wrong) {
+            assert!(
+                differential::search(puzzle, alternative, SEEDS, MAX_SIZE).is_some(),
+                "day_{:02} {} agreed on every input",
+                puzzle.day,
+                alternative.name
+            );
+        }
+    }
+}
+
+#[test]
+fn disagreements_are_shrunk() {
+    // a tolerance of one bad window lets a single window count as both increasing and decreasing,
+    // so the smallest report it gets wrong is one with two levels
+    let puzzle = registry::find(2).unwrap();
+    let tolerance = &puzzle.alternatives[0];
+    let disagreement = differential::search(puzzle, tolerance, SEEDS, MAX_SIZE).unwrap();
+    let reports: Vec<&str> = disagreement.input.lines().collect();
+    assert_eq!(reports.len(), 1, "{disagreement}");
+    assert_eq!(reports[0].split_whitespace().count(), 2, "{disagreement}");
+    assert_eq!(
+        (disagreement.expected.as_str(), disagreement.found.as_str()),
+        ("1", "0")
+    );
+}
+
+#[test]
+fn day_09_part_two_handles_a_disk_without_free_space() {
+    let puzzle = registry::find(9).unwrap();
+    let alt = &puzzle.alternatives[0];
+    for input in ["8\n", "12345\n", "\n"] {
+        assert_eq!(differential::compare(puzzle, alt, input), None, "{input:?}");
+    }
+}
+
+#[test]
+fn day_01_alternatives_agree_on_dense_lists() {
+    // generated inputs this big have fewer than 4 possible IDs per row, so the lists are counting
+    // sorted and looked up in an array, rather than radix sorted and merged
+    let puzzle = registry::find(1).unwrap();
+    let input = generate::generate(1, 0, 30_000).unwrap();
+    for alternative in puzzle.alternatives {
+        assert_eq!(
+            differential::compare(puzzle, alternative, &input),
+            None,
+            "{}",
+            alternative.name
+        );
+    }
+}
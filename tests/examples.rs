@@ -0,0 +1,78 @@
+//! Regression suite which runs every day's solution against its example input, i.e. every
+//! `day_XX_test.txt` in the inputs directory, and compares both parts against the `example`
+//! answers in `answers.txt`. Empty examples, as created by `aoc new`, are skipped. Record the
+//! answers for a new example with
+//! `cargo run --bin aoc -- run <day> --example --record`.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use aoc_2024::answers::{Answers, Status};
+use aoc_2024::input;
+use aoc_2024::registry;
+use aoc_2024::runner::Part;
+
+/// Every `day_XX_test.txt` in `inputs_dir`, with the day it belongs to.
+fn example_inputs(inputs_dir: &Path) -> Vec<(u8, PathBuf)> {
+    let mut examples: Vec<(u8, PathBuf)> = fs::read_dir(inputs_dir)
+        .expect("inputs directory should be readable")
+        .map(|entry| entry.expect("inputs directory entry").path())
+        .filter_map(|path| {
+            let name = path.file_name()?.to_str()?;
+            let day = name.strip_prefix("day_")?.strip_suffix("_test.txt")?;
+            Some((day.parse().ok()?, path))
+        })
+        .collect();
+    examples.sort();
+    examples
+}
+
+#[test]
+fn examples_match_recorded_answers() {
+    let inputs_dir = input::default_inputs_dir();
+    let answers = Answers::load(&inputs_dir).expect("answers file should be valid");
+    let examples = example_inputs(&inputs_dir);
+    assert!(
+        !examples.is_empty(),
+        "no example inputs found in {}",
+        inputs_dir.display()
+    );
+
+    // collect every mismatch rather than stopping at the first, so one run shows them all
+    let mut failures = Vec::new();
+    for (day, path) in examples {
+        let Some(puzzle) = registry::find(day) else {
+            failures.push(format!("{}: no solution registered", path.display()));
+            continue;
+        };
+        let text = fs::read_to_string(&path).expect("example input should be readable");
+        if text.trim().is_empty() {
+            // a day that was just started with `aoc new`, which has nothing to check yet
+            continue;
+        }
+        let mut run = match puzzle.run(&text, &Part::BOTH) {
+            Ok(run) => run,
+            Err(err) => {
+                failures.push(format!("{}: {err}", path.display()));
+                continue;
+            }
+        };
+        answers.annotate(&mut run, "example");
+        for part_run in &run.parts {
+            let label = format!("{} part {}", puzzle.name(), part_run.part);
+            match part_run.status() {
+                Status::Pass => {}
+                Status::Fail => failures.push(format!(
+                    "{label}: got {}, expected {}",
+                    part_run.answer,
+                    part_run.expected.as_deref().unwrap_or_default()
+                )),
+                Status::Unknown => failures.push(format!(
+                    "{label}: no example answer on record (got {})",
+                    part_run.answer
+                )),
+            }
+        }
+    }
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
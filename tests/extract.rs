@@ -0,0 +1,84 @@
+mod common;
+
+use std::fs;
+
+use aoc_2024::answers::Answers;
+use aoc_2024::extract::{self, Example, PuzzlePage};
+use aoc_2024::input;
+use aoc_2024::runner::Part;
+
+/// Trimmed down from a saved day 03 page, once both parts were solved.
+const PAGE: &str = r#"<!DOCTYPE html>
+<html lang="en-us"><head><title>Day 3 - Advent of Code 2024</title></head><body>
+<main>
+<article class="day-desc"><h2>--- Day 3: Mull It Over ---</h2>
+<p>For example, consider the following section of corrupted memory:</p>
+<pre><code>x<em>mul(2,4)</em>%&amp;mul[3,7]!@^do_not_<em>mul(5,5)</em>+mul(32,64]then(<em>mul(11,8)</em><em>mul(8,5)</em>)
+</code></pre>
+<p>Adding up the result of each instruction produces <code><em>161</em></code> (<code>2*4 + 5*5 + 11*8 + 8*5</code>).</p>
+</article>
+<p>Your puzzle answer was <code>175015740</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<pre><code>xmul(2,4)&amp;mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))
+</code></pre>
+<p>This time, the sum of the results is <code><em>48</em></code> (<code>2*4 + 8*5</code>).</p>
+</article>
+<p>Your puzzle answer was <code>112272912</code>.</p>
+</main></body></html>"#;
+
+#[test]
+fn code_blocks_and_answers_are_found_per_part() {
+    let page = PuzzlePage::parse(PAGE);
+    assert_eq!(page.parts.len(), 2);
+    assert_eq!(
+        page.code_blocks().collect::<Vec<_>>(),
+        [
+            "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))\n",
+            "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))\n",
+        ]
+    );
+    // the answers to the real input aren't highlighted, so they aren't mistaken for these
+    assert_eq!(page.parts[0].answer.as_deref(), Some("161"));
+    assert_eq!(page.parts[1].answer.as_deref(), Some("48"));
+
+    let example = page.example(1).unwrap();
+    assert_eq!(
+        example.answers,
+        [
+            (Part::One, String::from("161")),
+            (Part::Two, String::from("48"))
+        ]
+    );
+    assert_eq!(page.example(2), None);
+}
+
+#[test]
+fn save_writes_the_example_and_records_its_answers() {
+    let inputs_dir = common::temp_dir("extract");
+    let example = PuzzlePage::parse(PAGE).example(1).unwrap();
+
+    let saved = extract::save(&inputs_dir, 3, &example, false).unwrap();
+    assert_eq!(saved.recorded, Part::BOTH);
+    assert_eq!(
+        fs::read_to_string(input::input_path(&inputs_dir, 3, true)).unwrap(),
+        example.input
+    );
+    let answers = Answers::load(&inputs_dir).unwrap();
+    assert_eq!(answers.get(3, Part::Two, "example"), Some("48"));
+
+    // saving the same example again is harmless, but a different one needs overwriting
+    let saved = extract::save(&inputs_dir, 3, &example, false).unwrap();
+    assert!(saved.recorded.is_empty() && saved.kept.is_empty());
+    let other = Example {
+        input: String::from("mul(1,1)\n"),
+        answers: vec![(Part::One, String::from("1"))],
+    };
+    assert!(extract::save(&inputs_dir, 3, &other, false).is_err());
+    let saved = extract::save(&inputs_dir, 3, &other, true).unwrap();
+    assert_eq!(saved.recorded, [Part::One]);
+    assert!(saved.kept.is_empty());
+    // the old example's answers went with it, including the part the new one has no answer for
+    let answers = Answers::load(&inputs_dir).unwrap();
+    assert_eq!(answers.get(3, Part::One, "example"), Some("1"));
+    assert_eq!(answers.get(3, Part::Two, "example"), None);
+}
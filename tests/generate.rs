@@ -0,0 +1,50 @@
+use aoc_2024::generate;
+use aoc_2024::registry::{self, PUZZLES};
+use aoc_2024::rng::Rng;
+use aoc_2024::runner::Part;
+
+#[test]
+fn rng_matches_reference_splitmix64() {
+    // the first outputs of the reference implementation for seed 0
+    let mut rng = Rng::new(0);
+    assert_eq!(rng.next_u64(), 0xe220_a839_7b1d_cdaf);
+    assert_eq!(rng.next_u64(), 0x6e78_9e6a_a1b9_65f4);
+}
+
+/// Every day with a generator. Days which don't have one yet, like one just made by `aoc new`,
+/// are skipped, like empty examples are.
+fn generated_days() -> Vec<u8> {
+    let days: Vec<u8> = PUZZLES
+        .iter()
+        .map(|puzzle| puzzle.day)
+        .filter(|&day| generate::generator(day).is_some())
+        .collect();
+    assert!(!days.is_empty(), "no day has an input generator");
+    days
+}
+
+#[test]
+fn generators_are_deterministic() {
+    for day in generated_days() {
+        let first = generate::generate(day, 42, 20);
+        assert_eq!(first, generate::generate(day, 42, 20));
+        assert_ne!(first, generate::generate(day, 43, 20));
+    }
+}
+
+#[test]
+fn generated_inputs_are_valid_and_solvable() {
+    for seed in 0..5 {
+        for size in [1, 5, 25] {
+            for day in generated_days() {
+                let puzzle = registry::find(day).unwrap();
+                let input = generate::generate(day, seed, size).unwrap();
+                let context = format!("day {day} seed {seed} size {size}");
+                assert_eq!(puzzle.validate(&input), Vec::new(), "{context}");
+                if let Err(err) = puzzle.run(&input, &Part::BOTH) {
+                    panic!("{context}: {err}");
+                }
+            }
+        }
+    }
+}
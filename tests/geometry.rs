@@ -0,0 +1,46 @@
+use aoc_2024::geometry::{Direction, Point, Vector};
+use aoc_2024::grid::Grid;
+
+#[test]
+fn vector_arithmetic() {
+    let a = Point::new(3, 6);
+    let b = Point::new(4, 9);
+    let a_to_b = b - a;
+    assert_eq!(a_to_b, Vector::new(1, 3));
+    assert_eq!(a + a_to_b, b);
+    assert_eq!(b - a_to_b, a);
+    assert_eq!(a_to_b * 2, Vector::new(2, 6));
+    assert_eq!(-a_to_b, a - b);
+    assert_eq!(a_to_b + a_to_b, a_to_b * 2);
+}
+
+#[test]
+#[should_panic(expected = "overflowed")]
+fn overflow_panics_instead_of_wrapping() {
+    let _ = Point::new(isize::MAX, 0) + Vector::new(1, 0);
+}
+
+#[test]
+fn index_conversions_are_checked() {
+    assert_eq!(Point::new(2, 0).to_index(), Some((2, 0)));
+    assert_eq!(Point::new(-1, 0).to_index(), None);
+    assert_eq!(Point::from_index((1, 2)), Some(Point::new(1, 2)));
+    assert_eq!(Point::from_index((usize::MAX, 0)), None);
+
+    let grid = Grid::parse("ab\ncd").unwrap();
+    assert!(grid.contains(Point::new(1, 1)));
+    assert!(!grid.contains(Point::new(2, 1)));
+    assert!(!grid.contains(Point::new(0, -1)));
+    assert_eq!(grid[Point::new(1, 0)], 'c');
+}
+
+#[test]
+fn turning() {
+    for direction in Direction::ALL {
+        assert_eq!(direction.turn_right().turn_left(), direction);
+        assert_eq!(direction.opposite().opposite(), direction);
+        assert_eq!(direction.opposite().vector(), -direction.vector());
+    }
+    assert_eq!(Direction::North.turn_right(), Direction::East);
+    assert_eq!(Direction::North.turn_left(), Direction::West);
+}
@@ -0,0 +1,53 @@
+use aoc_2024::grid::Grid;
+
+const INPUT: &str = "abc\ndef\n";
+
+#[test]
+fn parse_ignores_trailing_whitespace_and_crlf() {
+    let grid = Grid::parse("abc \r\ndef\r\n\r\n").unwrap();
+    assert_eq!(grid, Grid::parse(INPUT).unwrap());
+    assert_eq!((grid.rows(), grid.cols()), (2, 3));
+}
+
+#[test]
+fn parse_rejects_ragged_rows() {
+    let err = Grid::parse("abc\nde\n").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "line 2: expected 3 columns like the first line, found 2"
+    );
+}
+
+#[test]
+fn display_round_trips() {
+    assert_eq!(Grid::parse(INPUT).unwrap().to_string(), INPUT);
+}
+
+#[test]
+fn get_and_offset_are_bounds_checked() {
+    let grid = Grid::parse(INPUT).unwrap();
+    assert_eq!(grid.get((1, 2)), Some(&'f'));
+    assert_eq!(grid.get((2, 0)), None);
+    assert_eq!(grid.offset((0, 0), (-1, 0)), None);
+    assert_eq!(grid.offset((0, 2), (0, 1)), None);
+    assert_eq!(grid.offset((0, 0), (1, 2)), Some((1, 2)));
+}
+
+#[test]
+fn neighbors_stay_on_the_grid() {
+    let grid = Grid::parse(INPUT).unwrap();
+    let corner: Vec<_> = grid.neighbors4((0, 0)).collect();
+    assert_eq!(corner, [(0, 1), (1, 0)]);
+    assert_eq!(grid.neighbors8((0, 0)).count(), 3);
+    assert_eq!(grid.neighbors8((1, 1)).count(), 5);
+}
+
+#[test]
+fn find_rows_and_columns() {
+    let grid = Grid::parse(INPUT).unwrap();
+    assert_eq!(grid.find(|&ch| ch == 'e'), Some((1, 1)));
+    assert_eq!(grid.find(|&ch| ch == 'z'), None);
+    assert_eq!(grid.row(1), ['d', 'e', 'f']);
+    let columns: Vec<String> = grid.iter_columns().map(|col| col.collect()).collect();
+    assert_eq!(columns, ["ad", "be", "cf"]);
+}
@@ -0,0 +1,69 @@
+use aoc_2024::days::{Day01, Day05, Day06, Day07, Day09};
+use aoc_2024::registry;
+use aoc_2024::runner::Part;
+use aoc_2024::Solution;
+
+#[test]
+fn errors_point_at_the_line_and_column() {
+    let err = Day01::parse("3   4\n4   x3\n").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "line 2, column 5: expected a number, found `x3`"
+    );
+
+    let err = Day07::parse("190: 10 19\n3267 81 40 27\n").unwrap_err();
+    assert_eq!(err.to_string(), "line 2: expected `target: numbers`");
+
+    let err = Day09::parse("2333133121414131402\n".replace('4', "-").as_str()).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "line 1, column 11: expected a digit, found `-`"
+    );
+}
+
+#[test]
+fn registry_errors_name_the_day() {
+    let puzzle = registry::find(5).unwrap();
+    let err = puzzle
+        .run("47|53\n97-13\n\n75,47\n", &Part::BOTH)
+        .unwrap_err();
+    assert_eq!(err.to_string(), "day_05 line 2: expected `a|b`");
+
+    let err = puzzle.run("47|53\n", &Part::BOTH).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "day_05 line 2: expected a blank line between the rules and the updates"
+    );
+}
+
+#[test]
+fn crlf_and_trailing_blank_lines_are_accepted() {
+    let input = "47|53\n97|47\n\n97,47,53\n";
+    let crlf = input.replace('\n', "\r\n") + "\r\n";
+    assert_eq!(Day05::parse(&crlf), Day05::parse(input));
+    assert_eq!(
+        Day01::parse("3   4\r\n4   3\r\n\r\n"),
+        Day01::parse("3   4\n4   3")
+    );
+}
+
+#[test]
+fn day_06_maps_need_exactly_one_guard() {
+    let err = Day06::parse("...\n.#.\n").unwrap_err();
+    assert_eq!(err.to_string(), "line 3: there is no guard `^` on the map");
+    let err = Day06::parse("..^\n.#.\n^..\n").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "line 3, column 1: another guard `^`, besides the one on line 1"
+    );
+}
+
+#[test]
+fn grids_too_small_for_an_x_mas_have_none() {
+    let puzzle = registry::find(4).unwrap();
+    for input in ["", "\n", "AB\n", "MAS\nMAS\n"] {
+        let run = puzzle.run(input, &Part::BOTH).unwrap();
+        let answers: Vec<&str> = run.parts.iter().map(|part| part.answer.as_str()).collect();
+        assert_eq!(answers, ["0", "0"], "{input:?}");
+    }
+}
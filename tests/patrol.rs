@@ -0,0 +1,64 @@
+use std::fs;
+use std::ops::ControlFlow;
+
+use aoc_2024::days::day_06::{self, Step};
+use aoc_2024::days::Day06;
+use aoc_2024::grid::Grid;
+use aoc_2024::input;
+use aoc_2024::Solution;
+
+fn example() -> Grid<char> {
+    let path = input::input_path(&input::default_inputs_dir(), 6, true);
+    Day06::parse(&fs::read_to_string(path).unwrap()).unwrap()
+}
+
+#[test]
+fn loop_obstructions_are_what_part_two_counts() {
+    let grid = example();
+    let obstructions = day_06::loop_obstructions(&grid);
+    assert_eq!(
+        obstructions,
+        [(6, 3), (7, 6), (7, 7), (8, 1), (8, 3), (9, 7)]
+    );
+    assert_eq!(obstructions.len() as u32, Day06::part_two(&grid));
+}
+
+#[test]
+fn patrol_ends_the_way_the_solution_does() {
+    let grid = example();
+    let mut visited = 0;
+    let ended = day_06::patrol(&grid, None, |frame| {
+        visited = frame.visited();
+        ControlFlow::Continue(())
+    });
+    assert_eq!(ended, Ok(Some(Step::Left)));
+    assert_eq!(visited, Day06::part_one(&grid));
+
+    let looped = day_06::patrol(&grid, Some((6, 3)), |_| ControlFlow::Continue(()));
+    assert_eq!(looped, Ok(Some(Step::Looped)));
+    assert!(day_06::patrol(&grid, Some((0, 4)), |_| ControlFlow::Continue(())).is_err());
+}
+
+#[test]
+fn frames_show_the_guard_trail_and_obstruction() {
+    let grid = example();
+    let mut frames = Vec::new();
+    let ended = day_06::patrol(&grid, Some((6, 3)), |frame| {
+        frames.push(frame.render(Some(4), false));
+        if frame.steps == 6 {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    });
+    // 4 by 4 around the guard, who starts on line 7, column 5
+    assert_eq!(ended, Ok(Some(Step::Turned)));
+    assert_eq!(
+        frames[0],
+        "....\n....\n.O^.\n....\nstep 0, 1 cells visited, facing North"
+    );
+    assert_eq!(
+        frames[6],
+        "..#.\n..>.\n..↑.\n#.↑.\nstep 6, 6 cells visited, facing East"
+    );
+}
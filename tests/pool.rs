@@ -0,0 +1,43 @@
+use std::thread;
+use std::time::Duration;
+
+use aoc_2024::pool;
+
+#[test]
+fn results_come_back_in_order() {
+    let items: Vec<u64> = (1..=20).collect();
+    let results = pool::run(&items, 4, |&n| n * n, |_, _| {});
+    let squares: Vec<u64> = results.into_iter().map(Result::unwrap).collect();
+    assert_eq!(squares, items.iter().map(|n| n * n).collect::<Vec<_>>());
+}
+
+#[test]
+fn a_panic_only_fails_its_own_item() {
+    let items = [1, 0, 2];
+    let results = pool::run(
+        &items,
+        2,
+        |&n| {
+            assert!(n != 0, "no zeroes allowed");
+            10 / n
+        },
+        |_, _| {},
+    );
+    assert_eq!(
+        results,
+        [Ok(10), Err(String::from("no zeroes allowed")), Ok(5)]
+    );
+}
+
+#[test]
+fn a_slow_item_does_not_hold_up_the_rest() {
+    let items = [300, 1, 1, 1, 1];
+    let mut finished = Vec::new();
+    pool::run(
+        &items,
+        2,
+        |&ms| thread::sleep(Duration::from_millis(ms)),
+        |i, _| finished.push(i),
+    );
+    assert_eq!(finished.last(), Some(&0), "finished in order {finished:?}");
+}
@@ -0,0 +1,104 @@
+use std::fs;
+
+use aoc_2024::grid::Grid;
+use aoc_2024::input;
+use aoc_2024::registry;
+use aoc_2024::render::{self, Color, Image, Layer};
+use aoc_2024::runner::Part;
+
+fn example(day: u8) -> Grid<char> {
+    let path = input::input_path(&input::default_inputs_dir(), day, true);
+    Grid::parse(&fs::read_to_string(path).unwrap()).unwrap()
+}
+
+#[test]
+fn colors_parse_and_print_as_hex() {
+    assert_eq!("#ff8800".parse(), Ok(Color::rgb(0xff, 0x88, 0x00)));
+    assert_eq!("0a0B0c".parse::<Color>().unwrap().to_string(), "#0a0b0c");
+    assert!("#ff88".parse::<Color>().is_err());
+    assert!("#gg8800".parse::<Color>().is_err());
+    assert_eq!(
+        render::parse_palette("#000000, #ffffff"),
+        Ok(vec![Color::rgb(0, 0, 0), Color::rgb(0xff, 0xff, 0xff)])
+    );
+}
+
+#[test]
+fn layers_mark_what_the_answers_count() {
+    // the layer which is the answer to each part of each day
+    let cases = [
+        (4, Part::Two, "x-mas", 5),
+        (6, Part::One, "visited", 1),
+        (6, Part::Two, "obstructions", 1),
+        (8, Part::One, "antinodes", 1),
+        (8, Part::Two, "harmonics", 1),
+    ];
+    for (day, part, name, cells_per_answer) in cases {
+        let grid = example(day);
+        let layers = render::day_layers(day).unwrap()(&grid);
+        let layer = layers.iter().find(|layer| layer.name == name).unwrap();
+        let text =
+            fs::read_to_string(input::input_path(&input::default_inputs_dir(), day, true)).unwrap();
+        let run = registry::find(day).unwrap().run(&text, &[part]).unwrap();
+        let answer: usize = run.parts[0].answer.parse().unwrap();
+        assert_eq!(
+            layer.cells.len(),
+            answer * cells_per_answer,
+            "day {day} {name}"
+        );
+    }
+}
+
+#[test]
+fn images_are_drawn_in_layer_order() {
+    let grid = Grid::parse("#.\n..\n").unwrap();
+    let (red, blue) = (Color::rgb(0xff, 0, 0), Color::rgb(0, 0, 0xff));
+    let mut bottom = Layer::new("bottom", &[red]);
+    bottom.mark((0, 1), 0);
+    bottom.mark((1, 1), 0);
+    let mut top = Layer::new("top", &[blue]);
+    top.mark((1, 1), 0);
+    let mut image = Image::new(&grid, vec![bottom, top]);
+    image.cell_size = 2;
+    image
+        .set_palette(
+            "grid",
+            vec![Color::rgb(0xff, 0xff, 0xff), Color::rgb(0, 0, 0)],
+        )
+        .unwrap();
+    assert!(image.set_palette("middle", vec![red]).is_err());
+
+    let svg = image.to_svg();
+    assert!(
+        svg.contains(
+            r##"<g id="top">
+    <rect x="2" y="2" width="2" height="2" fill="#0000ff"/>"##
+        ),
+        "{svg}"
+    );
+    assert!(svg.contains(r#"<text x="1" y="1">#</text>"#), "{svg}");
+
+    let png = image.to_png().unwrap();
+    let decoder = png::Decoder::new(png.as_slice());
+    let mut reader = decoder.read_info().unwrap();
+    let mut pixels = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut pixels).unwrap();
+    assert_eq!((info.width, info.height), (4, 4));
+    let pixel = |x: usize, y: usize| &pixels[(y * 4 + x) * 3..][..3];
+    assert_eq!(pixel(0, 0), [0, 0, 0]);
+    assert_eq!(pixel(3, 0), [0xff, 0, 0]);
+    assert_eq!(pixel(0, 3), [0xff, 0xff, 0xff]);
+    assert_eq!(pixel(3, 3), [0, 0, 0xff]);
+}
+
+#[test]
+fn images_without_pixels_are_an_error_not_a_panic() {
+    let grid = example(4);
+    let mut image = Image::new(&grid, Vec::new());
+    image.cell_size = 0;
+    assert!(image.to_png().is_err());
+
+    let empty = Grid::from_cells(0, 0, Vec::new());
+    let err = Image::new(&empty, Vec::new()).to_png().unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+}
@@ -0,0 +1,90 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use aoc_2024::answers;
+use aoc_2024::input::InputSource;
+use aoc_2024::report::{self, Record};
+use aoc_2024::runner::{DayRun, Part, PartRun};
+
+fn day_run() -> DayRun {
+    let part_run = |part, answer: &str, expected: Option<&str>, micros| PartRun {
+        part,
+        answer: answer.to_string(),
+        expected: expected.map(String::from),
+        elapsed: Duration::from_micros(micros),
+    };
+    DayRun {
+        day: 6,
+        input_hash: report::input_hash("a"),
+        parse_elapsed: Duration::from_micros(50),
+        parts: vec![
+            part_run(Part::One, "41", Some("41"), 10),
+            part_run(Part::Two, "7", Some("6"), 20),
+        ],
+    }
+}
+
+#[test]
+fn input_hash_is_fnv1a() {
+    assert_eq!(report::input_hash(""), "cbf29ce484222325");
+    assert_eq!(report::input_hash("a"), "af63dc4c8601ec8c");
+}
+
+#[test]
+fn records_flatten_every_part() {
+    let records = report::records(&[day_run()], "example", None);
+    assert_eq!(
+        records[1],
+        Record {
+            day: 6,
+            part: 2,
+            input: String::from("example"),
+            answer: String::from("7"),
+            expected: Some(String::from("6")),
+            status: String::from("FAIL"),
+            parse_nanos: 50_000,
+            solve_nanos: 20_000,
+            input_hash: String::from("af63dc4c8601ec8c"),
+            git_revision: None,
+        }
+    );
+}
+
+#[test]
+fn json_and_csv_escape_their_strings() {
+    let mut run = day_run();
+    run.parts.truncate(1);
+    run.parts[0].expected = None;
+    let records = report::records(&[run], "my \"odd\", input.txt", Some("abc123"));
+
+    assert_eq!(
+        report::to_json(&records),
+        "[\n  {\"day\": 6, \"part\": 1, \"input\": \"my \\\"odd\\\", input.txt\", \"answer\": \"41\", \
+         \"expected\": null, \"status\": \"UNKNOWN\", \"parse_ns\": 50000, \"solve_ns\": 10000, \
+         \"input_hash\": \"af63dc4c8601ec8c\", \"git_revision\": \"abc123\"}\n]\n"
+    );
+    assert_eq!(
+        report::to_csv(&records),
+        "day,part,input,answer,expected,status,parse_ns,solve_ns,input_hash,git_revision\n\
+         6,1,\"my \"\"odd\"\", input.txt\",41,,UNKNOWN,50000,10000,af63dc4c8601ec8c,abc123\n"
+    );
+    assert_eq!(report::to_json(&[]), "[]\n");
+}
+
+#[test]
+fn input_files_are_told_apart_by_their_contents() {
+    let key = |path: &str, text: &str| {
+        answers::input_key(&InputSource::File(PathBuf::from(path)), text).unwrap()
+    };
+    assert_eq!(key("a/big.txt", "1 2\n"), key("a/big.txt", "1 2\n"));
+    assert_ne!(key("a/big.txt", "1 2\n"), key("b/big.txt", "3 4\n"));
+    assert_eq!(
+        key("a/big.txt", "1 2\n"),
+        format!("big.txt@{}", report::input_hash("1 2\n"))
+    );
+    assert_eq!(
+        answers::input_key(&InputSource::Real, "1 2\n").as_deref(),
+        Some("real")
+    );
+    assert_eq!(answers::input_key(&InputSource::Stdin, "1 2\n"), None);
+}
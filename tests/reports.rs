@@ -0,0 +1,153 @@
+mod common;
+
+use aoc_2024::days::day_02::{self, Diagnosis, Explanation, Rule, SafetyPolicy};
+
+const EXAMPLE: &str = "7 6 4 2 1\n1 2 7 8 9\n9 7 6 2 1\n1 3 2 4 5\n8 6 4 4 1\n1 3 6 7 9\n";
+
+#[test]
+fn every_unsafe_report_is_explained() {
+    let explanations = day_02::explain(EXAMPLE, &SafetyPolicy::PART_TWO).unwrap();
+    let diagnosis = |window, rule, fix: Option<usize>| {
+        Some(Diagnosis {
+            window,
+            rule,
+            fix,
+            tolerance: 1,
+            saved: fix.is_some(),
+        })
+    };
+    let expected = [
+        (true, None),
+        (false, diagnosis(1, Rule::Step { from: 2, to: 7 }, None)),
+        (false, diagnosis(2, Rule::Step { from: 6, to: 2 }, None)),
+        (
+            true,
+            diagnosis(1, Rule::DirectionChanged { from: 3, to: 2 }, Some(1)),
+        ),
+        (true, diagnosis(2, Rule::Step { from: 4, to: 4 }, Some(2))),
+        (true, None),
+    ];
+    let expected: Vec<Explanation> = expected
+        .into_iter()
+        .enumerate()
+        .map(|(i, (safe, diagnosis))| Explanation {
+            line: i + 1,
+            safe,
+            diagnosis,
+        })
+        .collect();
+    assert_eq!(explanations, expected);
+    assert_eq!(
+        explanations[3].diagnosis.unwrap().to_string(),
+        "levels 2 and 3: 3 to 2 changes direction to go down, taking out level 2 fixes it"
+    );
+}
+
+#[test]
+fn explanations_keep_their_line_numbers() {
+    let explanations = day_02::explain("\n1 2 3\n\n3 3\n", &SafetyPolicy::PART_ONE).unwrap();
+    let lines: Vec<usize> = explanations.iter().map(|e| e.line).collect();
+    assert_eq!(lines, [2, 4]);
+}
+
+#[test]
+fn the_rules_can_be_changed() {
+    let report = [1, 5, 3, 7];
+    assert!(!SafetyPolicy::PART_TWO.is_safe(&report));
+    let zigzag = SafetyPolicy {
+        steps: 2..=4,
+        direction_can_change: true,
+        tolerance: 0,
+    };
+    assert!(zigzag.is_safe(&report));
+    assert_eq!(zigzag.diagnose(&report), None);
+
+    // a step of 0 keeps going the same way
+    let flat = SafetyPolicy {
+        steps: 0..=3,
+        ..SafetyPolicy::PART_ONE
+    };
+    assert!(flat.is_safe(&[1, 1, 2, 2, 3]));
+    assert_eq!(
+        flat.diagnose(&[1, 1, 2, 1]),
+        Some(Diagnosis {
+            window: 2,
+            rule: Rule::DirectionChanged { from: 2, to: 1 },
+            fix: Some(2),
+            tolerance: 0,
+            saved: false,
+        })
+    );
+    assert_eq!(
+        SafetyPolicy::PART_TWO.diagnose(&[4]),
+        Some(Diagnosis {
+            window: 0,
+            rule: Rule::TooFewLevels,
+            fix: None,
+            tolerance: 1,
+            saved: false,
+        })
+    );
+}
+
+#[test]
+fn a_fix_is_found_exactly_when_the_dampener_saves_the_report() {
+    // every report of 2 to 5 levels between 1 and 6, under a few policies
+    let policies = [
+        SafetyPolicy::PART_TWO,
+        SafetyPolicy {
+            steps: 0..=2,
+            ..SafetyPolicy::PART_TWO
+        },
+        SafetyPolicy {
+            direction_can_change: true,
+            ..SafetyPolicy::PART_TWO
+        },
+    ];
+    for len in 2..=5 {
+        for report in &common::reports(len, 6) {
+            for policy in &policies {
+                let diagnosis = policy.diagnose(report);
+                let fixable = diagnosis.is_none_or(|diagnosis| diagnosis.fix.is_some());
+                assert_eq!(policy.is_safe(report), fixable, "{report:?} {policy:?}");
+            }
+        }
+    }
+}
+
+#[test]
+fn explanations_are_worded_from_the_tolerance() {
+    let with_tolerance = |tolerance| SafetyPolicy {
+        tolerance,
+        ..SafetyPolicy::PART_ONE
+    };
+    let cases = [
+        (
+            0,
+            &[1, 3, 2, 4][..],
+            "levels 2 and 3: 3 to 2 changes direction to go down, taking out level 2 would fix \
+             it, but no levels can be taken out",
+        ),
+        (0, &[1, 9, 2, 9], "levels 1 and 2: 1 to 9 is a step of 8"),
+        (
+            1,
+            &[1, 9, 2, 9],
+            "levels 1 and 2: 1 to 9 is a step of 8, taking out one level doesn't fix it",
+        ),
+        (
+            2,
+            &[1, 9, 2, 9],
+            "levels 1 and 2: 1 to 9 is a step of 8, no one level fixes it, but taking out up to 2 \
+             levels does",
+        ),
+        (
+            2,
+            &[1, 9, 2, 9, 3, 9],
+            "levels 1 and 2: 1 to 9 is a step of 8, taking out up to 2 levels doesn't fix it",
+        ),
+    ];
+    for (tolerance, report, expected) in cases {
+        let diagnosis = with_tolerance(tolerance).diagnose(report).unwrap();
+        assert_eq!(diagnosis.to_string(), expected, "{report:?}");
+    }
+}
@@ -0,0 +1,121 @@
+mod common;
+
+use std::fs;
+use std::path::Path;
+use std::process::ExitCode;
+
+use aoc_2024::answers::{Answers, Status, ANSWERS_FILE};
+use aoc_2024::cli::{self, RunArgs};
+use aoc_2024::error::ParseError;
+use aoc_2024::input::InputArgs;
+use aoc_2024::registry::Puzzle;
+use aoc_2024::runner::Part;
+use aoc_2024::{scaffold, Solution, UNSOLVED};
+
+const MOD_RS: &str = "pub mod day_01;\n\npub use day_01::Day01;\n";
+const REGISTRY: &str = "pub const PUZZLES: &[Puzzle] = &[\n    puzzle!(1, Day01),\n];\n";
+
+/// A crate with just enough in it for `new_day` to register a day.
+fn fake_crate(name: &str, with_registry: bool) -> std::path::PathBuf {
+    let root = common::temp_dir(name);
+    fs::create_dir_all(root.join("src").join("days")).unwrap();
+    fs::create_dir_all(root.join("src").join("bin")).unwrap();
+    fs::write(root.join("src").join("days").join("mod.rs"), MOD_RS).unwrap();
+    if with_registry {
+        fs::write(root.join("src").join("registry.rs"), REGISTRY).unwrap();
+    }
+    root
+}
+
+fn read(root: &Path, path: &str) -> String {
+    fs::read_to_string(root.join(path)).unwrap()
+}
+
+#[test]
+fn new_days_are_registered_in_order() {
+    let root = fake_crate("scaffold-ok", true);
+    scaffold::new_day(&root, &root.join("input"), 3).unwrap();
+    assert_eq!(
+        read(&root, "src/days/mod.rs"),
+        "pub mod day_01;\npub mod day_03;\n\npub use day_01::Day01;\npub use day_03::Day03;\n"
+    );
+    assert!(
+        read(&root, "src/registry.rs").contains("    puzzle!(1, Day01),\n    puzzle!(3, Day03),\n")
+    );
+    assert!(root.join("src/days/day_03.rs").exists());
+    assert!(!root.join("src/days/mod.rs.tmp").exists());
+}
+
+#[test]
+fn a_failed_registration_leaves_nothing_behind() {
+    let root = fake_crate("scaffold-no-registry", false);
+    assert!(scaffold::new_day(&root, &root.join("input"), 3).is_err());
+    assert_eq!(read(&root, "src/days/mod.rs"), MOD_RS);
+    assert!(!root.join("src/days/day_03.rs").exists());
+    assert!(!root.join("src/bin/day_03.rs").exists());
+    assert!(!root.join("input").exists());
+}
+
+#[test]
+fn registration_is_all_or_nothing() {
+    // a directory in the way of the registry's temporary file makes writing it fail, after
+    // mod.rs's has been written
+    let root = fake_crate("scaffold-blocked", true);
+    fs::create_dir_all(root.join("src/registry.rs.tmp")).unwrap();
+    assert!(scaffold::new_day(&root, &root.join("input"), 3).is_err());
+    assert_eq!(read(&root, "src/days/mod.rs"), MOD_RS);
+    assert_eq!(read(&root, "src/registry.rs"), REGISTRY);
+    assert!(!root.join("src/days/mod.rs.tmp").exists());
+}
+
+/// What a scaffolded day solves its input with, as in `src/days/day_xx.template`, which can't be
+/// compiled into the tests itself.
+struct Scaffolded;
+
+impl Solution for Scaffolded {
+    type Input = Vec<String>;
+    type Answer = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part_one(_lines: &Self::Input) -> String {
+        String::from(UNSOLVED)
+    }
+
+    fn part_two(_lines: &Self::Input) -> String {
+        String::from(UNSOLVED)
+    }
+}
+
+#[test]
+fn unsolved_answers_are_never_recorded() {
+    let root = fake_crate("scaffold-record", true);
+    let inputs_dir = root.join("input");
+    scaffold::new_day(&root, &inputs_dir, 12).unwrap();
+    assert!(read(&root, "src/days/day_12.rs").contains("String::from(UNSOLVED)"));
+
+    let answers = "# answers\n01\t1\treal\t11\n12\t1\treal\t42\n";
+    fs::write(inputs_dir.join(ANSWERS_FILE), answers).unwrap();
+    let puzzle = Puzzle::new::<Scaffolded>(12);
+    let args = RunArgs {
+        record: true,
+        input: InputArgs {
+            inputs_dir: Some(inputs_dir.clone()),
+            ..InputArgs::default()
+        },
+        ..RunArgs::default()
+    };
+    assert_eq!(cli::run(&[&puzzle], &args), ExitCode::SUCCESS);
+    assert_eq!(read(&inputs_dir, ANSWERS_FILE), answers);
+
+    // and an unsolved part isn't compared with the answer on record, which is there from solving
+    // the day on another branch, say
+    let mut run = puzzle.run("", &Part::BOTH).unwrap();
+    Answers::load(&inputs_dir)
+        .unwrap()
+        .annotate(&mut run, "real");
+    let statuses: Vec<Status> = run.parts.iter().map(|part_run| part_run.status()).collect();
+    assert_eq!(statuses, [Status::Unknown, Status::Unknown]);
+}
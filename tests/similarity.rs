@@ -0,0 +1,107 @@
+use std::io::{self, Read};
+
+use aoc_2024::days::day_01;
+use aoc_2024::days::Day01;
+use aoc_2024::error::ParseError;
+use aoc_2024::generate;
+use aoc_2024::runner::Part;
+use aoc_2024::Solution;
+
+fn streamed(input: &str) -> io::Result<u64> {
+    day_01::similarity_streaming(input.as_bytes())
+}
+
+#[test]
+fn streaming_agrees_with_part_two() {
+    assert_eq!(
+        streamed("3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n").unwrap(),
+        31
+    );
+    for seed in 0..5 {
+        let input = generate::generate(1, seed, 2_000).unwrap();
+        let parsed = Day01::parse(&input).unwrap();
+        assert_eq!(streamed(&input).unwrap(), Day01::part_two(&parsed));
+    }
+}
+
+#[test]
+fn answers_past_u32_agree() {
+    // `aoc run 1 --generate 100000` overflowed a u32 in part two
+    let input = generate::generate(1, 0, 100_000).unwrap();
+    let parsed = Day01::parse(&input).unwrap();
+    let (part_one, part_two) = (Day01::part_one(&parsed), Day01::part_two(&parsed));
+    assert!(part_two > u32::MAX as u64);
+    assert_eq!(streamed(&input).unwrap(), part_two);
+    for alternative in Day01::ALTERNATIVES {
+        let expected = match alternative.part {
+            Part::One => part_one,
+            Part::Two => part_two,
+        };
+        let answer = (alternative.solve)(&input).unwrap();
+        assert_eq!(answer, expected.to_string(), "{}", alternative.name);
+    }
+}
+
+/// The same row over and over, without ever holding more than one copy of it in memory.
+struct Repeated {
+    row: &'static [u8],
+    rows_left: usize,
+    offset: usize,
+}
+
+impl Read for Repeated {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.rows_left == 0 {
+            return Ok(0);
+        }
+        let n = buf.len().min(self.row.len() - self.offset);
+        buf[..n].copy_from_slice(&self.row[self.offset..self.offset + n]);
+        self.offset += n;
+        if self.offset == self.row.len() {
+            self.offset = 0;
+            self.rows_left -= 1;
+        }
+        Ok(n)
+    }
+}
+
+#[test]
+fn streaming_score_does_not_overflow() {
+    let rows = 100_000;
+    let reader = io::BufReader::new(Repeated {
+        row: b"99999   99999\n",
+        rows_left: rows,
+        offset: 0,
+    });
+    let expected = 99_999 * rows as u64 * rows as u64;
+    assert!(expected > u32::MAX as u64);
+    assert_eq!(day_01::similarity_streaming(reader).unwrap(), expected);
+}
+
+#[test]
+fn streaming_points_at_bad_lines() {
+    let cases = [
+        (
+            "1   2\n\n3   x\n",
+            ParseError::new(3, "expected a number, found `x`").at_column(5),
+        ),
+        (
+            "1   2\n3\n",
+            ParseError::new(2, "expected 2 numbers, found 1"),
+        ),
+    ];
+    for (input, expected) in cases {
+        let err = streamed(input).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        let found = err.into_inner().unwrap().downcast::<ParseError>().unwrap();
+        assert_eq!(*found, expected, "{input:?}");
+    }
+}
+
+#[test]
+fn streaming_reads_lines_as_they_come() {
+    // a tiny buffer means lines arrive in pieces, and the last one has no newline
+    let input = "10 20\n20 10\n20 30";
+    let reader = io::BufReader::with_capacity(3, input.as_bytes());
+    assert_eq!(day_01::similarity_streaming(reader).unwrap(), 10 + 20 * 2);
+}
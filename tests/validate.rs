@@ -0,0 +1,66 @@
+use std::fs;
+
+use aoc_2024::input;
+use aoc_2024::registry::{self, PUZZLES};
+
+/// Every violation of `input` for `day`, as it would be printed.
+fn violations(day: u8, input: &str) -> Vec<String> {
+    let puzzle = registry::find(day).unwrap();
+    puzzle
+        .validate(input)
+        .iter()
+        .map(ToString::to_string)
+        .collect()
+}
+
+#[test]
+fn examples_are_valid() {
+    for puzzle in PUZZLES {
+        let path = input::input_path(&input::default_inputs_dir(), puzzle.day, true);
+        let text = fs::read_to_string(path).unwrap_or_default();
+        assert_eq!(violations(puzzle.day, &text), Vec::<String>::new());
+    }
+}
+
+#[test]
+fn day_05_rules_must_order_every_update() {
+    let input = "47|53\n53|13\n13|47\n97|13\n75|97\n97|75\n\n47,53,13\n97,47\n97,13\n75,97\n";
+    assert_eq!(
+        violations(5, input),
+        [
+            "day_05 line 8: the rules 47|53, 53|13 and 13|47 go round in a circle",
+            "day_05 line 9: no rule says whether 97 or 47 comes first",
+            "day_05 line 11: the rules 75|97 on line 5 and 97|75 on line 6 contradict each other",
+        ]
+    );
+}
+
+#[test]
+fn day_06_guards_face_north() {
+    assert_eq!(
+        violations(6, "..^\n.#.\n..>\n"),
+        ["day_06 line 3: unexpected `>` at column 3, the map should only have `.`, `#` and a `^`"]
+    );
+    // a map without exactly one guard doesn't even parse, and that's left to the parse error
+    assert!(violations(6, "...\n.#.\n").is_empty());
+    assert!(violations(6, "..^\n.#.\n^..\n").is_empty());
+}
+
+#[test]
+fn day_08_antennas_are_letters_or_digits() {
+    assert_eq!(
+        violations(8, "..a.\n.#..\n"),
+        ["day_08 line 2: unexpected `#` at column 2, antennas should be letters or digits"]
+    );
+    // a ragged map doesn't even parse, and that's left to the parse error
+    assert!(violations(8, "..a.\n...\n").is_empty());
+}
+
+#[test]
+fn day_09_is_a_single_line() {
+    assert!(violations(9, "12345\n\n").is_empty());
+    assert_eq!(
+        violations(9, "12345\n678\n"),
+        ["day_09 line 2: the disk map should be a single line, but there is more after it"]
+    );
+}